@@ -0,0 +1,130 @@
+//! With the `solutions` feature, generates the list of solutions that are compiled into the main binary as modules,
+//! so that `all` and `time` can run every day in a single process.
+//! With the `test_lib` feature, it also renders the bundled templates, so that the tests of the library compile them.
+use std::{env, fs, path::Path};
+
+fn main() {
+    // NOTE: without this, cargo would re-run the script on every change to the package.
+    println!("cargo:rerun-if-changed=build.rs");
+
+    if env::var_os("CARGO_FEATURE_TEST_LIB").is_some() {
+        render_templates();
+    }
+
+    // NOTE: only `all` and `time` enable the feature, so a day that does not compile only breaks its own run elsewhere.
+    if env::var_os("CARGO_FEATURE_SOLUTIONS").is_some() {
+        register_solutions();
+    }
+}
+
+/// Write `solutions.rs`, which includes every solution in `src/bin` as a module and lists their registry entries.
+fn register_solutions() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let bin_dir = Path::new(&manifest_dir).join("src").join("bin");
+
+    println!("cargo:rerun-if-changed={}", bin_dir.display());
+
+    let mut days: Vec<(u16, u8, String)> = fs::read_dir(&bin_dir)
+        .map(|entries| {
+            entries
+                .filter_map(Result::ok)
+                .filter_map(|entry| {
+                    let path = entry.path();
+                    if path.extension()? != "rs" {
+                        return None;
+                    }
+                    let stem = path.file_stem()?.to_str()?;
+                    // NOTE: only scaffolded solutions (e.g. `2024-01.rs`) are registered.
+                    let (year, day) = stem.split_once('-')?;
+                    if year.len() != 4 || day.len() != 2 {
+                        return None;
+                    }
+                    let year: u16 = year.parse().ok()?;
+                    let day: u8 = day.parse().ok()?;
+                    (year >= 2015 && (1..=25).contains(&day))
+                        .then(|| (year, day, path.to_string_lossy().into_owned()))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    days.sort_unstable();
+
+    let mut modules = String::new();
+    let mut entries = String::new();
+
+    for (year, day, path) in &days {
+        modules.push_str(&format!("#[path = {path:?}]\nmod y{year}_day_{day:02};\n"));
+        entries.push_str(&format!("    y{year}_day_{day:02}::SOLUTION,\n"));
+    }
+
+    let generated = format!(
+        "// @generated by build.rs from the solutions in `src/bin`.\n\
+         {modules}\n\
+         pub const SOLUTIONS: &[advent_of_code::template::registry::Solution] = &[\n{entries}];\n"
+    );
+
+    let out_path = Path::new(&env::var("OUT_DIR").unwrap()).join("solutions.rs");
+    fs::write(out_path, generated).unwrap();
+}
+
+/// Render the default template and the templates in `templates` for day 1 of 2024, without a title or example answers.
+/// NOTE: the placeholders must match `templates::Placeholders::render`, the tests of the library check that they do.
+fn render_templates() {
+    let manifest_dir = Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap()).to_path_buf();
+    let out_dir = Path::new(&env::var("OUT_DIR").unwrap()).join("templates");
+    fs::create_dir_all(&out_dir).unwrap();
+
+    let default = manifest_dir.join("src").join("template.txt");
+    let templates_dir = manifest_dir.join("templates");
+
+    println!("cargo:rerun-if-changed={}", default.display());
+    println!("cargo:rerun-if-changed={}", templates_dir.display());
+
+    let mut templates = vec![("default".to_string(), default)];
+    templates.extend(
+        fs::read_dir(&templates_dir)
+            .map(|entries| {
+                entries
+                    .filter_map(Result::ok)
+                    .map(|entry| entry.path())
+                    .filter(|path| path.extension().is_some_and(|ext| ext == "txt"))
+                    .filter_map(|path| Some((path.file_stem()?.to_str()?.to_string(), path)))
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default(),
+    );
+    templates.sort();
+
+    let mut modules = String::new();
+    let mut entries = String::new();
+
+    for (name, path) in &templates {
+        let rendered = fs::read_to_string(path)
+            .unwrap()
+            .replace("%YEAR%", "2024")
+            .replace("%DAY_NUMBER%", "1")
+            .replace("%DAY%", "01")
+            .replace("%TITLE%", "Day 1")
+            .replace("%ANSWER_TYPE%", "u32")
+            .replace("%PART_ONE_ANSWER%", "None")
+            .replace("%PART_TWO_ANSWER%", "None");
+
+        let rendered_path = out_dir.join(format!("{name}.rs"));
+        fs::write(&rendered_path, rendered).unwrap();
+
+        modules.push_str(&format!("#[path = {rendered_path:?}]\nmod {name};\n"));
+        entries.push_str(&format!(
+            "    ({name:?}, {path:?}, include_str!({rendered_path:?})),\n"
+        ));
+    }
+
+    let generated = format!(
+        "// @generated by build.rs from the bundled templates.\n\
+         {modules}\n\
+         /// Name, path and rendered contents of every bundled template.\n\
+         pub const RENDERED: &[(&str, &str, &str)] = &[\n{entries}];\n"
+    );
+
+    fs::write(out_dir.join("mod.rs"), generated).unwrap();
+}
@@ -1,10 +1,9 @@
 use advent_of_code::solution;
 use std::collections::HashMap;
 
-solution!(1);
+solution!(1, year: 2024, parse: parse_input);
 
-pub fn part_one(input: &str) -> Option<i32> {
-    let (a, b) = parse_input(input);
+pub fn part_one((a, b): &(Vec<i32>, Vec<i32>)) -> Option<i32> {
     let result = a
         .iter()
         .zip(b.iter())
@@ -13,8 +12,7 @@ pub fn part_one(input: &str) -> Option<i32> {
     Some(result)
 }
 
-pub fn part_two(input: &str) -> Option<i32> {
-    let (list1, list2) = parse_input(input);
+pub fn part_two((list1, list2): &(Vec<i32>, Vec<i32>)) -> Option<i32> {
     let list2_freq = counts(list2);
 
     let result = list1
@@ -25,7 +23,7 @@ pub fn part_two(input: &str) -> Option<i32> {
     Some(result)
 }
 
-fn counts(vec: Vec<i32>) -> HashMap<i32, i32> {
+fn counts(vec: &[i32]) -> HashMap<i32, i32> {
     let mut freq = HashMap::new();
     for num in vec.iter() {
         *freq.entry(*num).or_insert(0) += 1;
@@ -33,7 +31,7 @@ fn counts(vec: Vec<i32>) -> HashMap<i32, i32> {
     freq
 }
 
-fn parse_input(text: &str) -> (Vec<i32>, Vec<i32>) {
+pub fn parse_input(text: &str) -> (Vec<i32>, Vec<i32>) {
     text.lines()
         .map(|line| {
             line.split("   ")
@@ -50,13 +48,19 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY)).unwrap();
+        let result = part_one(&parse_input(&advent_of_code::template::read_file(
+            "examples", YEAR, DAY,
+        )))
+        .unwrap();
         assert_eq!(result, 309560);
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY)).unwrap();
+        let result = part_two(&parse_input(&advent_of_code::template::read_file(
+            "examples", YEAR, DAY,
+        )))
+        .unwrap();
         assert_eq!(result, 76056);
     }
 }
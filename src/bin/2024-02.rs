@@ -1,4 +1,4 @@
-advent_of_code::solution!(2);
+advent_of_code::solution!(2, year: 2024);
 
 pub fn part_one(input: &str) -> Option<usize> {
     let result = read_input(input)
@@ -60,13 +60,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY)).unwrap();
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY)).unwrap();
         assert_eq!(result, 2);
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(4));
     }
 }
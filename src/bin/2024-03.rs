@@ -1,4 +1,4 @@
-advent_of_code::solution!(3);
+advent_of_code::solution!(3, year: 2024);
 
 pub fn part_one(input: &str) -> Option<u32> {
     let result = extract_tuples(input).iter().map(|(x, y)| x * y).sum();
@@ -29,13 +29,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY)).unwrap();
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY)).unwrap();
         assert_eq!(result, 161);
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(48));
     }
 }
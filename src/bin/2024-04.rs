@@ -1,4 +1,4 @@
-advent_of_code::solution!(4);
+advent_of_code::solution!(4, year: 2024);
 
 pub fn part_one(input: &str) -> Option<u32> {
     let matrix = convert_to_matrix(input);
@@ -128,13 +128,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY)).unwrap();
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY)).unwrap();
         assert_eq!(result, 18);
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY)).unwrap();
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY)).unwrap();
         assert_eq!(result, 9);
     }
 }
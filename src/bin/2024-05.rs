@@ -3,14 +3,14 @@ use std::cmp::Ordering::{Equal, Greater, Less};
 use std::collections::HashMap;
 use std::num::ParseIntError;
 
-advent_of_code::solution!(5);
+advent_of_code::solution!(5, year: 2024);
 
-pub fn part_one(input: &str) -> Option<u32> {
-    let rules = parse_rules(input).ok()?;
+pub fn part_one(input: &str) -> Result<u32, ParseIntError> {
+    let rules = parse_rules(input)?;
     // sort rules by the first number
     let rules = rules.into_iter().sorted_by_key(|(x, _)| *x).collect_vec();
 
-    let instructions_sets = parse_instructions(input).ok()?;
+    let instructions_sets = parse_instructions(input)?;
 
     let result = instructions_sets
         .iter()
@@ -18,7 +18,7 @@ pub fn part_one(input: &str) -> Option<u32> {
         .map(|it| it[it.len() / 2])
         .sum();
 
-    Some(result)
+    Ok(result)
 }
 
 fn parse_rules(input: &str) -> Result<Vec<(u32, u32)>, ParseIntError> {
@@ -62,9 +62,9 @@ fn get_positions(vec: &[u32]) -> HashMap<u32, usize> {
         })
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    let rules = parse_rules(input).ok()?;
-    let instructions_sets = parse_instructions(input).ok()?;
+pub fn part_two(input: &str) -> Result<u32, ParseIntError> {
+    let rules = parse_rules(input)?;
+    let instructions_sets = parse_instructions(input)?;
 
     let result = instructions_sets
         .iter()
@@ -72,7 +72,7 @@ pub fn part_two(input: &str) -> Option<u32> {
         .map(|it| fix_instruction_set(&rules, it))
         .map(|it| it[it.len() / 2])
         .sum();
-    Some(result)
+    Ok(result)
 }
 
 fn fix_instruction_set(rules: &[(u32, u32)], instructions: &[u32]) -> Vec<u32> {
@@ -97,13 +97,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY)).unwrap();
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY)).unwrap();
         assert_eq!(result, 143);
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY)).unwrap();
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY)).unwrap();
         assert_eq!(result, 123);
     }
 }
@@ -1,7 +1,7 @@
 use advent_of_code::gameguard::direction::Direction;
 use advent_of_code::gameguard::game::Game;
 
-advent_of_code::solution!(6);
+advent_of_code::solution!(6, year: 2024);
 
 pub fn part_one(input: &str) -> Option<u32> {
     let mut game = initialize_game(input);
@@ -76,13 +76,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY)).unwrap();
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY)).unwrap();
         assert_eq!(result, 41);
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY)).unwrap();
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY)).unwrap();
         assert_eq!(result, 6);
     }
 }
@@ -1,4 +1,4 @@
-advent_of_code::solution!(7);
+advent_of_code::solution!(7, year: 2024);
 
 pub fn part_one(input: &str) -> Option<u64> {
     let allowed_operations = vec![|a, b| a + b, |a, b| a * b];
@@ -79,13 +79,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY)).unwrap();
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY)).unwrap();
         assert_eq!(result, 3749);
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY)).unwrap();
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY)).unwrap();
         assert_eq!(result, 11387);
     }
 }
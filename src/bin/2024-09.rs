@@ -2,18 +2,18 @@ use itertools::Itertools;
 use std::ops::Div;
 use std::string::String;
 
-advent_of_code::solution!(9);
+advent_of_code::solution!(9, year: 2024);
 
-pub fn part_one(input: &str) -> Option<u128> {
-    let expanded = expand(input).ok()?;
+pub fn part_one(input: &str) -> Result<u128, String> {
+    let expanded = expand(input)?;
     let compacted = compact(&expanded);
-    Some(checksum(&compacted))
+    Ok(checksum(&compacted))
 }
 
-pub fn part_two(input: &str) -> Option<u128> {
-    let expanded = expand(input).ok()?;
+pub fn part_two(input: &str) -> Result<u128, String> {
+    let expanded = expand(input)?;
     let compacted = compact_by_blocks(&expanded);
-    Some(checksum(&compacted))
+    Ok(checksum(&compacted))
 }
 
 fn expand(input: &str) -> Result<Vec<Option<usize>>, String> {
@@ -128,13 +128,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY)).unwrap();
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY)).unwrap();
         assert_eq!(result, 1928);
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY)).unwrap();
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY)).unwrap();
         assert_eq!(result, 2858);
     }
 }
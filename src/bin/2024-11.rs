@@ -1,7 +1,7 @@
 use std::collections::HashMap;
 use std::ops::Div;
 
-advent_of_code::solution!(11);
+advent_of_code::solution!(11, year: 2024);
 
 pub fn part_one(input: &str) -> Option<usize> {
     let stones = read_input(input);
@@ -88,13 +88,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY)).unwrap();
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY)).unwrap();
         assert_eq!(result, 189541);
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY)).unwrap();
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY)).unwrap();
         assert_eq!(result, 226596360258785);
     }
 }
@@ -1,4 +1,4 @@
-advent_of_code::solution!(12);
+advent_of_code::solution!(12, year: 2024);
 
 pub fn part_one(input: &str) -> Option<usize> {
     let mut input = read_input(input);
@@ -203,13 +203,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("inputs", DAY));
+        let result = part_one(&advent_of_code::template::read_file("inputs", YEAR, DAY));
         assert_eq!(result, None);
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("inputs", DAY));
+        let result = part_two(&advent_of_code::template::read_file("inputs", YEAR, DAY));
         assert_eq!(result, None);
     }
 }
\ No newline at end of file
@@ -1,6 +1,6 @@
 use itertools::Itertools;
 
-advent_of_code::solution!(13);
+advent_of_code::solution!(13, year: 2024);
 
 pub fn part_one(input: &str) -> Option<u128> {
     solve(parse_input(input))
@@ -105,13 +105,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY)).unwrap();
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY)).unwrap();
         assert_eq!(result, 480);
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY)).unwrap();
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY)).unwrap();
         assert_eq!(result, 875318608908);
     }
 }
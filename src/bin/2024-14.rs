@@ -1,7 +1,6 @@
 use std::fmt::{Display, Formatter};
-use std::io::Write;
 
-advent_of_code::solution!(14);
+advent_of_code::solution!(14, year: 2024);
 
 pub fn part_one(input: &str) -> Option<usize> {
     let mut map = Map::new(input, (101, 103));
@@ -17,8 +16,6 @@ pub fn part_one(input: &str) -> Option<usize> {
 }
 
 pub fn part_two(input: &str) -> Option<u32> {
-    let mut file = std::fs::File::create("output.txt").unwrap();
-
     let mut map = Map::new(input, (101, 103));
 
 
@@ -27,10 +24,6 @@ pub fn part_two(input: &str) -> Option<u32> {
         let (q1, q2, q3, q4) = map.count_quadrants();
 
         let randomnesss = q1 * q2 * q3 * q4;
-        if randomnesss < 95_779_830 {
-            writeln!(file, "After {} seconds, randomness {}:", i, randomnesss).unwrap();
-            writeln!(file, "{}", map).unwrap();
-        }
     }
 
     None
@@ -159,13 +152,13 @@ mod tests {
     #[test]
     fn test_part_one() {
         println!("{}", -3 % 11);
-        let result = part_one(&advent_of_code::template::read_file("inputs", DAY));
+        let result = part_one(&advent_of_code::template::read_file("inputs", YEAR, DAY));
         assert_eq!(result, None);
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, None);
     }
 }
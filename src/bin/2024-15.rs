@@ -1,6 +1,6 @@
 use std::fmt::Display;
 
-advent_of_code::solution!(15);
+advent_of_code::solution!(15, year: 2024);
 
 pub fn part_one(input: &str) -> Option<usize> {
     let mut simulation = Simulation::of(input);
@@ -321,13 +321,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY)).unwrap();
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY)).unwrap();
         assert_eq!(result, 10092);
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY)).unwrap();
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY)).unwrap();
         assert_eq!(result, 9021);
     }
 }
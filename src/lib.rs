@@ -1,3 +1,7 @@
+// NOTE: the templates that are compiled by the tests refer to the library by its name.
+#[cfg(feature = "test_lib")]
+extern crate self as advent_of_code;
+
 pub mod gameguard;
 pub mod template;
 
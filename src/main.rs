@@ -1,4 +1,6 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve, time};
+use advent_of_code::template::commands::{
+    all, download, examples, read, readme, scaffold, solve, status, test_day, time, watch,
+};
 use args::{parse, AppArguments};
 
 #[cfg(feature = "today")]
@@ -7,8 +9,12 @@ use advent_of_code::template::Day;
 use std::process;
 
 mod args {
-    use advent_of_code::template::Day;
-    use std::process;
+    use advent_of_code::template::{
+        commands::{test_day::parse_days, time::DEFAULT_REPORT_DIR},
+        runner::{parse_part, parse_timeout, Input},
+        Day, Year,
+    };
+    use std::{path::PathBuf, process, time::Duration};
 
     pub enum AppArguments {
         Download {
@@ -17,44 +23,131 @@ mod args {
         Read {
             day: Day,
         },
+        Examples {
+            day: Day,
+        },
+        Watch {
+            day: Day,
+            release: bool,
+        },
+        TestDay {
+            days: Option<Vec<Day>>,
+        },
+        Status,
+        Readme,
         Scaffold {
             day: Day,
             download: bool,
             overwrite: bool,
+            template: Option<String>,
         },
         Solve {
             day: Day,
             release: bool,
             dhat: bool,
             submit: Option<u8>,
+            timeout: Option<Duration>,
+            accept: bool,
+            part: Option<u8>,
+            input: Input,
         },
         All {
             release: bool,
+            jobs: usize,
+            timeout: Option<Duration>,
+            accept: bool,
+            part: Option<u8>,
         },
         Time {
             all: bool,
             day: Option<Day>,
             store: bool,
+            jobs: usize,
+            timeout: Option<Duration>,
+            dhat: bool,
+            compare: bool,
+            threshold: Option<f64>,
+            part: Option<u8>,
+            history: bool,
+            report: Option<PathBuf>,
         },
         #[cfg(feature = "today")]
         Today,
     }
 
-    pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
+    /// Every command works on a single year, passed as `--year` or read from `AOC_YEAR`.
+    fn parse_year(args: &mut pico_args::Arguments) -> Result<Year, Box<dyn std::error::Error>> {
+        if let Some(year) = args.opt_value_from_str("--year")? {
+            return Ok(year);
+        }
+
+        Ok(Year::from_env()?.ok_or("no year specified, pass `--year` or set `AOC_YEAR`.")?)
+    }
+
+    /// Parse `--input <path>`, `--input -` for stdin or `--example [<n>]`.
+    fn parse_input(args: &mut pico_args::Arguments) -> Result<Input, Box<dyn std::error::Error>> {
+        let path: Option<String> = args.opt_value_from_str("--input")?;
+
+        // the number of the example is optional.
+        let example = match args.opt_value_from_str::<_, u8>("--example") {
+            Ok(Some(0)) => return Err("examples are numbered from 1.".into()),
+            Ok(Some(n)) => Some(Some(n)),
+            Ok(None) => None,
+            Err(_) => args.contains("--example").then_some(None),
+        };
+
+        match (path, example) {
+            (Some(_), Some(_)) => Err("`--input` and `--example` can not be combined.".into()),
+            (Some(path), None) if path == "-" => Ok(Input::Stdin),
+            (Some(path), None) => Ok(Input::File(path)),
+            (None, Some(n)) => Ok(Input::Example(n)),
+            (None, None) => Ok(Input::Puzzle),
+        }
+    }
+
+    pub fn parse() -> Result<(Year, AppArguments), Box<dyn std::error::Error>> {
         let mut args = pico_args::Arguments::from_env();
 
         let app_args = match args.subcommand()?.as_deref() {
             Some("all") => AppArguments::All {
                 release: args.contains("--release"),
+                jobs: args.opt_value_from_str("--jobs")?.unwrap_or(1),
+                timeout: args.opt_value_from_fn("--timeout", parse_timeout)?,
+                accept: args.contains("--accept"),
+                part: args.opt_value_from_fn("--part", parse_part)?,
             },
             Some("time") => {
                 let all = args.contains("--all");
                 let store = args.contains("--store");
+                let jobs = args.opt_value_from_str("--jobs")?.unwrap_or(1);
+                let timeout = args.opt_value_from_fn("--timeout", parse_timeout)?;
+                let dhat = args.contains("--dhat");
+                let compare = args.contains("--compare");
+                let threshold = args.opt_value_from_str("--threshold")?;
+                let part = args.opt_value_from_fn("--part", parse_part)?;
+                let history = args.contains("--history");
+
+                // the directory of the report is optional.
+                let report = match args.opt_value_from_str::<_, PathBuf>("--report") {
+                    Ok(Some(dir)) => Some(dir),
+                    Ok(None) => None,
+                    Err(_) => args
+                        .contains("--report")
+                        .then(|| PathBuf::from(DEFAULT_REPORT_DIR)),
+                };
 
                 AppArguments::Time {
                     all,
                     day: args.opt_free_from_str()?,
                     store,
+                    jobs,
+                    timeout,
+                    dhat,
+                    compare,
+                    threshold,
+                    part,
+                    history,
+                    report,
                 }
             }
             Some("download") => AppArguments::Download {
@@ -63,17 +156,55 @@ mod args {
             Some("read") => AppArguments::Read {
                 day: args.free_from_str()?,
             },
-            Some("scaffold") => AppArguments::Scaffold {
+            Some("examples") => AppArguments::Examples {
                 day: args.free_from_str()?,
-                download: args.contains("--download"),
-                overwrite: args.contains("--overwrite"),
             },
-            Some("solve") => AppArguments::Solve {
+            Some("watch") => AppArguments::Watch {
                 day: args.free_from_str()?,
                 release: args.contains("--release"),
-                submit: args.opt_value_from_str("--submit")?,
-                dhat: args.contains("--dhat"),
             },
+            Some("test-day") => AppArguments::TestDay {
+                days: args.opt_free_from_fn(parse_days)?,
+            },
+            Some("status") => AppArguments::Status,
+            Some("readme") => AppArguments::Readme,
+            Some("scaffold") => AppArguments::Scaffold {
+                day: args.free_from_str()?,
+                download: args.contains("--download"),
+                overwrite: args.contains("--overwrite"),
+                template: args.opt_value_from_str("--template")?,
+            },
+            Some("solve") => {
+                let day = args.free_from_str()?;
+                let release = args.contains("--release");
+                let submit = args.opt_value_from_str("--submit")?;
+                let dhat = args.contains("--dhat");
+                let timeout = args.opt_value_from_fn("--timeout", parse_timeout)?;
+                let accept = args.contains("--accept");
+                let part = args.opt_value_from_fn("--part", parse_part)?;
+                let input = parse_input(&mut args)?;
+
+                // NOTE: only the parts that are run can be submitted.
+                if let (Some(submit), Some(part)) = (submit, part) {
+                    if submit != part {
+                        return Err(format!(
+                            "`--submit {submit}` can not be combined with `--part {part}`."
+                        )
+                        .into());
+                    }
+                }
+
+                AppArguments::Solve {
+                    day,
+                    release,
+                    submit,
+                    dhat,
+                    timeout,
+                    accept,
+                    part,
+                    input,
+                }
+            }
             #[cfg(feature = "today")]
             Some("today") => AppArguments::Today,
             Some(x) => {
@@ -86,49 +217,121 @@ mod args {
             }
         };
 
+        let year = parse_year(&mut args)?;
+
         let remaining = args.finish();
         if !remaining.is_empty() {
             eprintln!("Warning: unknown argument(s): {remaining:?}.");
         }
 
-        Ok(app_args)
+        Ok((year, app_args))
     }
 }
 
+/// Solution bins, pulled in as modules so that `all` and `time` can run them in-process.
+/// Only the aliases of these commands enable the `solutions` feature, other commands run the bins.
+/// Lints are reported when the solutions are compiled as their own binaries.
+#[cfg(all(feature = "solutions", not(test)))]
+#[allow(warnings, clippy::all, clippy::pedantic)]
+mod solutions {
+    include!(concat!(env!("OUT_DIR"), "/solutions.rs"));
+}
+
 fn main() {
+    #[cfg(all(feature = "solutions", not(test)))]
+    advent_of_code::template::registry::register(solutions::SOLUTIONS);
+
     match parse() {
         Err(err) => {
             eprintln!("Error: {err}");
             std::process::exit(1);
         }
-        Ok(args) => match args {
-            AppArguments::All { release } => all::handle(release),
-            AppArguments::Time { day, all, store } => time::handle(day, all, store),
-            AppArguments::Download { day } => download::handle(day),
-            AppArguments::Read { day } => read::handle(day),
+        Ok((year, args)) => match args {
+            AppArguments::All {
+                release,
+                jobs,
+                timeout,
+                accept,
+                part,
+            } => all::handle(year, release, jobs, timeout, accept, part),
+            AppArguments::Time {
+                day,
+                all,
+                store,
+                jobs,
+                timeout,
+                dhat,
+                compare,
+                threshold,
+                part,
+                history,
+                report,
+            } => time::handle(time::Options {
+                year,
+                day,
+                run_all: all,
+                store,
+                jobs,
+                timeout,
+                dhat,
+                compare,
+                threshold,
+                part,
+                history,
+                report,
+            }),
+            AppArguments::Download { day } => download::handle(year, day),
+            AppArguments::Read { day } => read::handle(year, day),
+            AppArguments::Examples { day } => examples::handle(year, day),
+            AppArguments::Watch { day, release } => watch::handle(year, day, release),
+            AppArguments::TestDay { days } => test_day::handle(year, days),
+            AppArguments::Status => status::handle(year),
+            AppArguments::Readme => readme::handle(),
             AppArguments::Scaffold {
                 day,
                 download,
                 overwrite,
+                template,
             } => {
-                scaffold::handle(day, overwrite);
+                // download first, so the scaffolded tests can use the answers to the examples.
                 if download {
-                    download::handle(day);
+                    download::handle(year, day);
+                    if let Err(e) = examples::extract(year, day) {
+                        eprintln!("{e}");
+                    }
                 }
+                scaffold::handle(year, day, overwrite, template.as_deref());
             }
             AppArguments::Solve {
                 day,
                 release,
                 dhat,
                 submit,
-            } => solve::handle(day, release, dhat, submit),
+                timeout,
+                accept,
+                part,
+                input,
+            } => solve::handle(solve::Options {
+                year,
+                day,
+                release,
+                dhat,
+                submit_part: submit,
+                timeout,
+                accept,
+                part,
+                input,
+            }),
             #[cfg(feature = "today")]
             AppArguments::Today => {
                 match Day::today() {
                     Some(day) => {
-                        scaffold::handle(day, false);
-                        download::handle(day);
-                        read::handle(day)
+                        download::handle(year, day);
+                        if let Err(e) = examples::extract(year, day) {
+                            eprintln!("{e}");
+                        }
+                        scaffold::handle(year, day, false, None);
+                        read::handle(year, day)
                     }
                     None => {
                         eprintln!(
@@ -0,0 +1,445 @@
+/// Built-in client for the advent of code website. Downloads inputs and puzzle descriptions and submits answers.
+/// Authenticates with the session cookie of a logged-in browser session.
+use std::{
+    env,
+    fmt::Display,
+    fs,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use crate::template::markdown::{html_to_markdown, puzzle_to_markdown};
+use crate::template::{get_data_path, Day, Year};
+
+/// Environment variable that holds the session cookie.
+pub const SESSION_ENV: &str = "AOC_SESSION";
+
+/// Environment variable that points to a file holding the session cookie, `~/.adventofcode.session` by default.
+pub const SESSION_FILE_ENV: &str = "AOC_SESSION_FILE";
+
+/// Environment variable that overrides the base URL of the website, e.g. to test against a local server.
+pub const BASE_URL_ENV: &str = "AOC_BASE_URL";
+
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Default session file, shared with aoc-cli.
+const DEFAULT_SESSION_FILE: &str = ".adventofcode.session";
+
+/// The website asks automated tools to identify themselves.
+const USER_AGENT: &str = concat!(
+    "github.com/fspoettel/advent-of-code-rust ",
+    env!("CARGO_PKG_NAME"),
+    "/",
+    env!("CARGO_PKG_VERSION")
+);
+
+#[derive(Debug)]
+pub enum AocClientError {
+    /// No session cookie is configured.
+    MissingSession,
+    /// The website responded with an error status.
+    BadStatus(u16, String),
+    /// The website could not be reached.
+    Transport(String),
+    IO(std::io::Error),
+}
+
+impl Display for AocClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocClientError::MissingSession => write!(
+                f,
+                "no session cookie found. Set {SESSION_ENV} or store it in ~/{DEFAULT_SESSION_FILE}."
+            ),
+            AocClientError::BadStatus(status, message) if message.is_empty() => {
+                write!(f, "advent of code responded with status {status}.")
+            }
+            AocClientError::BadStatus(status, message) => {
+                write!(f, "advent of code responded with status {status}: {message}")
+            }
+            AocClientError::Transport(e) => write!(f, "could not reach advent of code: {e}"),
+            AocClientError::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl From<std::io::Error> for AocClientError {
+    fn from(e: std::io::Error) -> Self {
+        AocClientError::IO(e)
+    }
+}
+
+impl From<ureq::Error> for AocClientError {
+    fn from(e: ureq::Error) -> Self {
+        match e {
+            ureq::Error::Status(status, response) => AocClientError::BadStatus(
+                status,
+                response
+                    .into_string()
+                    .map(|body| html_to_markdown(&body))
+                    .unwrap_or_default(),
+            ),
+            ureq::Error::Transport(e) => AocClientError::Transport(e.to_string()),
+        }
+    }
+}
+
+/// Verdict of the website on a submitted answer.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Verdict {
+    Correct,
+    Incorrect,
+    /// The answer is wrong and too high.
+    TooHigh,
+    /// The answer is wrong and too low.
+    TooLow,
+    /// The answer was not checked, as a wrong answer was submitted recently. Holds the remaining wait time, if given.
+    TooRecent(Option<Duration>),
+    /// The part is already solved or not unlocked yet.
+    WrongLevel,
+    Unknown,
+}
+
+impl Verdict {
+    /// Read the verdict from the message of the website.
+    fn from_message(message: &str) -> Self {
+        if message.contains("That's the right answer") {
+            Verdict::Correct
+        } else if message.contains("That's not the right answer") {
+            if message.contains("too high") {
+                Verdict::TooHigh
+            } else if message.contains("too low") {
+                Verdict::TooLow
+            } else {
+                Verdict::Incorrect
+            }
+        } else if message.contains("You gave an answer too recently") {
+            Verdict::TooRecent(parse_wait(message))
+        } else if message.contains("You don't seem to be solving the right level") {
+            Verdict::WrongLevel
+        } else {
+            Verdict::Unknown
+        }
+    }
+}
+
+/// Parse the remaining wait time from a message like "You have 1m 20s left to wait."
+fn parse_wait(message: &str) -> Option<Duration> {
+    let start = message.find("You have ")? + "You have ".len();
+    let end = message[start..].find(" left to wait")? + start;
+
+    message[start..end]
+        .split_whitespace()
+        .map(|part| {
+            let (value, unit) = part.split_at(part.find(|c: char| !c.is_ascii_digit())?);
+            let value: u64 = value.parse().ok()?;
+            match unit {
+                "h" => Some(value * 3600),
+                "m" => Some(value * 60),
+                "s" => Some(value),
+                _ => None,
+            }
+        })
+        .sum::<Option<u64>>()
+        .map(Duration::from_secs)
+}
+
+/// Parse the wait time after a wrong answer from a message like "Please wait 5 minutes before trying again."
+fn parse_retry_wait(message: &str) -> Option<Duration> {
+    let end = message.find(" before trying again")?;
+    let start = message[..end].rfind("wait ")? + "wait ".len();
+
+    let (value, unit) = message[start..end].split_once(' ')?;
+    let value: u64 = match value {
+        "one" => 1,
+        value => value.parse().ok()?,
+    };
+
+    match unit {
+        "second" | "seconds" => Some(Duration::from_secs(value)),
+        "minute" | "minutes" => Some(Duration::from_secs(value * 60)),
+        "hour" | "hours" => Some(Duration::from_secs(value * 3600)),
+        _ => None,
+    }
+}
+
+/// Result of a submission.
+#[derive(Clone, Debug)]
+pub struct Submission {
+    pub verdict: Verdict,
+    /// Message of the website, converted to markdown.
+    pub message: String,
+}
+
+impl Submission {
+    /// Time until the next answer for the part is accepted, if the website asks to wait.
+    pub fn cooldown(&self) -> Option<Duration> {
+        match self.verdict {
+            Verdict::TooRecent(wait) => wait,
+            Verdict::Incorrect | Verdict::TooHigh | Verdict::TooLow => {
+                parse_retry_wait(&self.message)
+            }
+            _ => None,
+        }
+    }
+}
+
+pub struct AocClient {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl AocClient {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+            agent: ureq::AgentBuilder::new()
+                .user_agent(USER_AGENT)
+                .timeout(Duration::from_secs(30))
+                .build(),
+        }
+    }
+
+    /// Create a client from the environment. The session cookie is read from `AOC_SESSION`,
+    /// or from the file at `AOC_SESSION_FILE` (`~/.adventofcode.session` by default).
+    pub fn from_env() -> Result<Self, AocClientError> {
+        let base_url = env::var(BASE_URL_ENV).unwrap_or_else(|_| DEFAULT_BASE_URL.into());
+
+        let session = match env::var(SESSION_ENV) {
+            Ok(session) => session,
+            Err(_) => get_session_file()
+                .and_then(|path| fs::read_to_string(path).ok())
+                .ok_or(AocClientError::MissingSession)?,
+        };
+
+        if session.trim().is_empty() {
+            return Err(AocClientError::MissingSession);
+        }
+
+        Ok(Self::new(&base_url, &session))
+    }
+
+    fn day_url(&self, year: Year, day: Day) -> String {
+        format!("{}/{year}/day/{}", self.base_url, day.into_inner())
+    }
+
+    fn get(&self, url: &str) -> Result<String, AocClientError> {
+        let response = self
+            .agent
+            .get(url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call()?;
+        Ok(response.into_string()?)
+    }
+
+    /// Fetch the puzzle input of a day.
+    pub fn get_input(&self, year: Year, day: Day) -> Result<String, AocClientError> {
+        self.get(&format!("{}/input", self.day_url(year, day)))
+    }
+
+    /// Fetch the puzzle description of a day as markdown. Includes part two once it is unlocked.
+    pub fn get_puzzle(&self, year: Year, day: Day) -> Result<String, AocClientError> {
+        let html = self.get(&self.day_url(year, day))?;
+        Ok(puzzle_to_markdown(&html))
+    }
+
+    /// Submit an answer for a part of a day.
+    pub fn submit(
+        &self,
+        year: Year,
+        day: Day,
+        part: u8,
+        answer: &str,
+    ) -> Result<Submission, AocClientError> {
+        let html = self
+            .agent
+            .post(&format!("{}/answer", self.day_url(year, day)))
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])?
+            .into_string()?;
+
+        let message = puzzle_to_markdown(&html);
+
+        Ok(Submission {
+            verdict: Verdict::from_message(&message),
+            message,
+        })
+    }
+
+    /// Download the input and puzzle description of a day to the data folder of its year.
+    pub fn download(&self, year: Year, day: Day) -> Result<(), AocClientError> {
+        let input_path = get_input_path(year, day);
+        let puzzle_path = get_puzzle_path(year, day);
+
+        write_file(&input_path, &self.get_input(year, day)?)?;
+        write_file(&puzzle_path, &self.get_puzzle(year, day)?)?;
+
+        println!("🎄 Successfully wrote input to \"{}\".", &input_path);
+        println!("🎄 Successfully wrote puzzle to \"{}\".", &puzzle_path);
+        Ok(())
+    }
+
+    /// Download the puzzle description of a day to the data folder of its year and print it.
+    pub fn read(&self, year: Year, day: Day) -> Result<(), AocClientError> {
+        let puzzle = self.get_puzzle(year, day)?;
+        write_file(&get_puzzle_path(year, day), &puzzle)?;
+        println!("{puzzle}");
+        Ok(())
+    }
+}
+
+fn get_session_file() -> Option<PathBuf> {
+    if let Ok(path) = env::var(SESSION_FILE_ENV) {
+        return Some(PathBuf::from(path));
+    }
+
+    let home = env::var("HOME").or_else(|_| env::var("USERPROFILE")).ok()?;
+    Some(Path::new(&home).join(DEFAULT_SESSION_FILE))
+}
+
+fn get_input_path(year: Year, day: Day) -> String {
+    get_data_path(year, "inputs", &format!("{day}.txt"))
+}
+
+fn get_puzzle_path(year: Year, day: Day) -> String {
+    get_data_path(year, "puzzles", &format!("{day}.md"))
+}
+
+fn write_file(path: &str, contents: &str) -> Result<(), std::io::Error> {
+    if let Some(parent) = Path::new(path).parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, contents)
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        thread::{self, JoinHandle},
+        time::Duration,
+    };
+
+    use super::{parse_retry_wait, parse_wait, AocClient, AocClientError, Verdict};
+    use crate::{day, year};
+
+    /// Serve canned responses on a local port, one per connection. Returns the base URL and the received requests.
+    fn fake_server(responses: Vec<(u16, &'static str)>) -> (String, JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            let mut requests = vec![];
+
+            for (status, body) in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+                let mut request = String::new();
+                let mut content_length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if let Some(value) = line.to_lowercase().strip_prefix("content-length:") {
+                        content_length = value.trim().parse().unwrap();
+                    }
+                    request.push_str(&line);
+                    if line == "\r\n" {
+                        break;
+                    }
+                }
+
+                let mut request_body = vec![0; content_length];
+                reader.read_exact(&mut request_body).unwrap();
+                request.push_str(&String::from_utf8(request_body).unwrap());
+                requests.push(request);
+
+                write!(
+                    stream,
+                    "HTTP/1.1 {status} OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                )
+                .unwrap();
+            }
+
+            requests
+        });
+
+        (base_url, handle)
+    }
+
+    #[test]
+    fn fetches_inputs() {
+        let (base_url, server) = fake_server(vec![(200, "1 2\n3 4\n")]);
+        let client = AocClient::new(&base_url, "secret\n");
+
+        let input = client.get_input(year!(2023), day!(5)).unwrap();
+        assert_eq!(input, "1 2\n3 4\n");
+
+        let requests = server.join().unwrap();
+        assert_eq!(requests[0].starts_with("GET /2023/day/5/input "), true);
+        assert_eq!(requests[0].contains("session=secret\r\n"), true);
+    }
+
+    #[test]
+    fn reports_bad_status() {
+        let (base_url, server) = fake_server(vec![(400, "<p>Please log in.</p>")]);
+        let client = AocClient::new(&base_url, "secret");
+
+        match client.get_input(year!(2023), day!(5)) {
+            Err(AocClientError::BadStatus(400, message)) => assert_eq!(message, "Please log in."),
+            x => panic!("unexpected result: {x:?}"),
+        }
+
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn submits_answers() {
+        let (base_url, server) = fake_server(vec![(
+            200,
+            "<main><article><p>That's not the right answer; your answer is too high. <a href=\"/2024/day/1\">[Return to Day 1]</a></p></article></main>",
+        )]);
+        let client = AocClient::new(&base_url, "secret");
+
+        let submission = client.submit(year!(2024), day!(1), 2, "42").unwrap();
+        assert_eq!(submission.verdict, Verdict::TooHigh);
+        assert_eq!(
+            submission.message,
+            "That's not the right answer; your answer is too high. [[Return to Day 1]](/2024/day/1)"
+        );
+
+        let requests = server.join().unwrap();
+        assert_eq!(requests[0].starts_with("POST /2024/day/1/answer "), true);
+        assert_eq!(requests[0].ends_with("level=2&answer=42"), true);
+    }
+
+    #[test]
+    fn reads_verdicts() {
+        assert_eq!(
+            Verdict::from_message("That's the right answer! You are one gold star closer."),
+            Verdict::Correct
+        );
+        assert_eq!(
+            Verdict::from_message("That's not the right answer. If you're stuck, ..."),
+            Verdict::Incorrect
+        );
+        assert_eq!(
+            Verdict::from_message("You gave an answer too recently. You have 1m 5s left to wait."),
+            Verdict::TooRecent(Some(Duration::from_secs(65)))
+        );
+        assert_eq!(
+            Verdict::from_message("You don't seem to be solving the right level."),
+            Verdict::WrongLevel
+        );
+        assert_eq!(
+            parse_wait("You have 37s left to wait."),
+            Some(Duration::from_secs(37))
+        );
+    }
+}
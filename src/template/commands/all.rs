@@ -1,5 +1,33 @@
-use crate::template::{all_days, run_multi::run_multi};
+use std::process;
+use std::time::Duration;
 
-pub fn handle(is_release: bool) {
-    run_multi(&all_days().collect(), is_release, false);
+use crate::template::run_multi::{run_multi, Profile};
+use crate::template::{all_days, runner::RunOptions, Year};
+
+pub fn handle(
+    year: Year,
+    is_release: bool,
+    jobs: usize,
+    timeout: Option<Duration>,
+    accept: bool,
+    part: Option<u8>,
+) {
+    let options = RunOptions {
+        is_timed: false,
+        timeout,
+        accept,
+        part,
+    };
+
+    let run = run_multi(
+        year,
+        &all_days().collect(),
+        Profile::from_release(is_release),
+        jobs,
+        options,
+    );
+
+    if !run.wrong_answers.is_empty() || !run.failed_days.is_empty() {
+        process::exit(1);
+    }
 }
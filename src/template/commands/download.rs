@@ -1,14 +1,26 @@
-use crate::template::{aoc_cli, Day};
+use crate::template::aoc_client::{AocClient, AocClientError};
+use crate::template::{aoc_cli, Day, Year};
 use std::process;
 
-pub fn handle(day: Day) {
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
-    }
+pub fn handle(year: Year, day: Day) {
+    let client = match AocClient::from_env() {
+        Ok(client) => client,
+        // fall back to aoc-cli, which manages its own session.
+        Err(AocClientError::MissingSession) if aoc_cli::check().is_ok() => {
+            if let Err(e) = aoc_cli::download(year, day) {
+                eprintln!("failed to call aoc-cli: {e}");
+                process::exit(1);
+            };
+            return;
+        }
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    };
 
-    if let Err(e) = aoc_cli::download(day) {
-        eprintln!("failed to call aoc-cli: {e}");
+    if let Err(e) = client.download(year, day) {
+        eprintln!("failed to download puzzle: {e}");
         process::exit(1);
-    };
+    }
 }
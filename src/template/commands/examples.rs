@@ -0,0 +1,122 @@
+use std::{
+    fs,
+    io::{self, IsTerminal, Write},
+    process,
+};
+
+use crate::template::answers::Answers;
+use crate::template::examples::{code_blocks, highlighted_answers, CodeBlock};
+use crate::template::{get_data_path, Day, Year};
+
+/// Number of lines of a code block that are shown when choosing between examples.
+const PREVIEW_LINES: usize = 5;
+
+pub fn handle(year: Year, day: Day) {
+    if let Err(e) = extract(year, day) {
+        eprintln!("{e}");
+        process::exit(1);
+    }
+}
+
+/// Write the examples of a day's puzzle description to its example files and record their answers.
+pub fn extract(year: Year, day: Day) -> Result<(), String> {
+    let puzzle_path = get_data_path(year, "puzzles", &format!("{day}.md"));
+
+    let puzzle = fs::read_to_string(&puzzle_path).map_err(|_| {
+        format!("Could not read \"{puzzle_path}\". Run `cargo download {day}` first.")
+    })?;
+
+    let blocks = code_blocks(&puzzle);
+
+    let selected = match blocks.len() {
+        0 => return Err(format!("No code blocks found in \"{puzzle_path}\".")),
+        1 => blocks,
+        _ => choose_blocks(blocks),
+    };
+
+    fs::create_dir_all(get_data_path(year, "examples", ""))
+        .map_err(|e| format!("Failed to create examples folder: {e}"))?;
+
+    for (index, block) in selected.iter().enumerate() {
+        let file_name = match index {
+            0 => format!("{day}.txt"),
+            n => format!("{day}-{}.txt", n + 1),
+        };
+        let example_path = get_data_path(year, "examples", &file_name);
+
+        fs::write(&example_path, &block.content)
+            .map_err(|e| format!("Failed to write example file: {e}"))?;
+        println!("Wrote example to \"{example_path}\"");
+    }
+
+    let mut answers = Answers::read_examples_file(year)
+        .map_err(|e| format!("Not storing example answers, as they could not be read: {e}"))?;
+
+    for (part, answer) in (1..).zip(highlighted_answers(&puzzle)) {
+        if let Some(answer) = answer {
+            println!("Part {part}: the example's answer is {answer}");
+            answers.set(day, part, answer);
+        }
+    }
+
+    answers
+        .store_examples_file(year)
+        .map_err(|e| format!("Failed to store example answers: {e}"))
+}
+
+/// Let the user pick the code blocks that are examples. The first selected block becomes the main example.
+/// Without a terminal, the first block is used.
+fn choose_blocks(blocks: Vec<CodeBlock>) -> Vec<CodeBlock> {
+    if !io::stdin().is_terminal() {
+        println!(
+            "Found {} code blocks, using the first one as the example.",
+            blocks.len()
+        );
+        return blocks.into_iter().take(1).collect();
+    }
+
+    println!("Found {} code blocks:", blocks.len());
+
+    for (index, block) in blocks.iter().enumerate() {
+        let line_count = block.content.lines().count();
+        println!(
+            "\n[{}] part {}, {line_count} line(s)",
+            index + 1,
+            block.part
+        );
+        for line in block.content.lines().take(PREVIEW_LINES) {
+            println!("    {line}");
+        }
+        if line_count > PREVIEW_LINES {
+            println!("    ...");
+        }
+    }
+
+    loop {
+        print!("\nSelect the example(s) to write, e.g. `1` or `1,3` [1]: ");
+        io::stdout().flush().unwrap();
+
+        let mut selection = String::new();
+        if io::stdin().read_line(&mut selection).unwrap_or(0) == 0 {
+            process::exit(1);
+        }
+
+        let selection = selection.trim();
+        if selection.is_empty() {
+            return blocks.into_iter().take(1).collect();
+        }
+
+        let indices: Option<Vec<usize>> = selection
+            .split(',')
+            .map(|index| {
+                let index: usize = index.trim().parse().ok()?;
+                (1..=blocks.len()).contains(&index).then_some(index - 1)
+            })
+            .collect();
+
+        match indices {
+            Some(indices) => return indices.iter().map(|&i| blocks[i].clone()).collect(),
+            None => eprintln!("Expecting block numbers between 1 and {}.", blocks.len()),
+        }
+    }
+}
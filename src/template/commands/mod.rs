@@ -1,6 +1,11 @@
 pub mod all;
 pub mod download;
+pub mod examples;
 pub mod read;
+pub mod readme;
 pub mod scaffold;
 pub mod solve;
+pub mod status;
+pub mod test_day;
 pub mod time;
+pub mod watch;
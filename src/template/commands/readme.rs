@@ -0,0 +1,17 @@
+use std::process;
+
+use crate::template::readme::{self, Error};
+
+pub fn handle() {
+    match readme::update() {
+        Ok(()) => println!("Updated readme."),
+        Err(Error::Parser(e)) => {
+            eprintln!("Failed to update the readme: {e}");
+            process::exit(1);
+        }
+        Err(Error::IO(e)) => {
+            eprintln!("Failed to update the readme: {e}");
+            process::exit(1);
+        }
+    }
+}
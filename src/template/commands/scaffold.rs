@@ -1,13 +1,12 @@
 use std::{
-    fs::{File, OpenOptions},
-    io::Write,
+    fs::{self, File, OpenOptions},
+    io::{ErrorKind, Write},
     process,
 };
 
-use crate::template::Day;
-
-const MODULE_TEMPLATE: &str =
-    include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/template.txt"));
+use crate::template::answers::Answers;
+use crate::template::templates::{self, Placeholders, DEFAULT_TEMPLATE};
+use crate::template::{get_bin_name, get_data_path, Day, Year};
 
 fn safe_create_file(path: &str, overwrite: bool) -> Result<File, std::io::Error> {
     let mut file = OpenOptions::new();
@@ -19,18 +18,35 @@ fn safe_create_file(path: &str, overwrite: bool) -> Result<File, std::io::Error>
     file.truncate(true).write(true).open(path)
 }
 
-fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new()
-        .write(true)
-        .create(true)
-        .truncate(true)
-        .open(path)
+/// Existing files are kept, e.g. inputs and examples that were downloaded before scaffolding.
+fn create_file(path: &str) -> Result<Option<File>, std::io::Error> {
+    match OpenOptions::new().write(true).create_new(true).open(path) {
+        Ok(file) => Ok(Some(file)),
+        Err(e) if e.kind() == ErrorKind::AlreadyExists => Ok(None),
+        Err(e) => Err(e),
+    }
 }
 
-pub fn handle(day: Day, overwrite: bool) {
-    let input_path = format!("data/inputs/{day}.txt");
-    let example_path = format!("data/examples/{day}.txt");
-    let module_path = format!("src/bin/{day}.rs");
+pub fn handle(year: Year, day: Day, overwrite: bool, template: Option<&str>) {
+    let input_path = get_data_path(year, "inputs", &format!("{day}.txt"));
+    let example_path = get_data_path(year, "examples", &format!("{day}.txt"));
+    let puzzle_path = get_data_path(year, "puzzles", &format!("{day}.md"));
+    let module_path = format!("src/bin/{}.rs", get_bin_name(year, day));
+
+    let contents = match templates::load(template.unwrap_or(DEFAULT_TEMPLATE)) {
+        Ok(contents) => contents,
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    };
+
+    for folder in ["inputs", "examples", "puzzles"] {
+        if let Err(e) = fs::create_dir_all(get_data_path(year, folder, "")) {
+            eprintln!("Failed to create data folder: {e}");
+            process::exit(1);
+        }
+    }
 
     let mut file = match safe_create_file(&module_path, overwrite) {
         Ok(file) => file,
@@ -40,11 +56,22 @@ pub fn handle(day: Day, overwrite: bool) {
         }
     };
 
-    match file.write_all(
-        MODULE_TEMPLATE
-            .replace("%DAY_NUMBER%", &day.into_inner().to_string())
-            .as_bytes(),
-    ) {
+    // recorded by `cargo examples`.
+    let example_answers = Answers::read_examples_file(year).unwrap_or_else(|e| {
+        eprintln!("Failed to read the example answers: {e}");
+        Answers::default()
+    });
+
+    let placeholders = Placeholders {
+        year,
+        day,
+        title: fs::read_to_string(&puzzle_path)
+            .ok()
+            .and_then(|markdown| templates::puzzle_title(&markdown)),
+        answers: [1, 2].map(|part| example_answers.get(day, part).map(String::from)),
+    };
+
+    match file.write_all(placeholders.render(&contents).as_bytes()) {
         Ok(()) => {
             println!("Created module file \"{}\"", &module_path);
         }
@@ -55,9 +82,10 @@ pub fn handle(day: Day, overwrite: bool) {
     }
 
     match create_file(&input_path) {
-        Ok(_) => {
+        Ok(Some(_)) => {
             println!("Created empty input file \"{}\"", &input_path);
         }
+        Ok(None) => {}
         Err(e) => {
             eprintln!("Failed to create input file: {e}");
             process::exit(1);
@@ -65,15 +93,31 @@ pub fn handle(day: Day, overwrite: bool) {
     }
 
     match create_file(&example_path) {
-        Ok(_) => {
+        Ok(Some(_)) => {
             println!("Created empty example file \"{}\"", &example_path);
         }
+        Ok(None) => {}
         Err(e) => {
             eprintln!("Failed to create example file: {e}");
             process::exit(1);
         }
     }
 
+    // the input may have been downloaded before scaffolding, e.g. by `--download`.
+    if template.is_none() {
+        let suggestion = fs::read_to_string(&input_path)
+            .ok()
+            .and_then(|input| templates::suggest(&input))
+            .filter(|name| templates::available().iter().any(|t| t == name));
+
+        if let Some(name) = suggestion {
+            println!(
+                "💡 The input looks like it fits the `{name}` template, \
+                scaffold with `--template {name} --overwrite` to use it."
+            );
+        }
+    }
+
     println!("---");
-    println!("🎄 Type `cargo solve {day}` to run your solution.");
+    println!("🎄 Type `cargo solve {day} --year {year}` to run your solution.");
 }
@@ -1,9 +1,46 @@
-use std::process::{Command, Stdio};
+use std::process::{self, Command, Stdio};
+use std::time::Duration;
 
-use crate::template::Day;
+use crate::template::runner::Input;
+use crate::template::{get_bin_name, Day, Year};
 
-pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
-    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day.to_string()];
+/// Options of the `solve` command.
+#[derive(Clone, Debug)]
+pub struct Options {
+    pub year: Year,
+    pub day: Day,
+    pub release: bool,
+    /// Collect heap statistics with DHAT.
+    pub dhat: bool,
+    /// Part to submit the answer of.
+    pub submit_part: Option<u8>,
+    pub timeout: Option<Duration>,
+    /// Record the answers as the known answers.
+    pub accept: bool,
+    /// Only run this part.
+    pub part: Option<u8>,
+    /// Input to run the solution against.
+    pub input: Input,
+}
+
+pub fn handle(options: Options) {
+    let Options {
+        year,
+        day,
+        release,
+        dhat,
+        submit_part,
+        timeout,
+        accept,
+        part,
+        input,
+    } = options;
+
+    let mut cmd_args = vec![
+        "run".to_string(),
+        "--bin".to_string(),
+        get_bin_name(year, day),
+    ];
 
     if dhat {
         cmd_args.extend([
@@ -23,6 +60,22 @@ pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
         cmd_args.push(submit_part.to_string());
     }
 
+    if let Some(timeout) = timeout {
+        cmd_args.push("--timeout".to_string());
+        cmd_args.push(timeout.as_secs_f64().to_string());
+    }
+
+    if accept {
+        cmd_args.push("--accept".to_string());
+    }
+
+    if let Some(part) = part {
+        cmd_args.push("--part".to_string());
+        cmd_args.push(part.to_string());
+    }
+
+    cmd_args.extend(input.to_args());
+
     let mut cmd = Command::new("cargo")
         .args(&cmd_args)
         .stdout(Stdio::inherit())
@@ -30,5 +83,10 @@ pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
         .spawn()
         .unwrap();
 
-    cmd.wait().unwrap();
+    let status = cmd.wait().unwrap();
+
+    // e.g. a part did not reproduce its known answer.
+    if !status.success() {
+        process::exit(status.code().unwrap_or(1));
+    }
 }
@@ -0,0 +1,22 @@
+use crate::template::answers::Answers;
+use crate::template::status::{format_calendar, DayStatus};
+use crate::template::timings::Timings;
+use crate::template::{all_days, Year, ANSI_BOLD, ANSI_RESET};
+
+pub fn handle(year: Year) {
+    let answers = Answers::read_from_file(year).unwrap_or_else(|e| {
+        eprintln!("Failed to read the known answers: {e}");
+        Answers::default()
+    });
+    let timings = Timings::read_from_file(year).unwrap_or_else(|e| {
+        eprintln!("Failed to read the stored timings: {e}");
+        Timings::default()
+    });
+
+    let statuses: Vec<DayStatus> = all_days()
+        .map(|day| DayStatus::collect(year, day, &answers, &timings))
+        .collect();
+
+    println!("{ANSI_BOLD}Advent of Code {year}{ANSI_RESET}\n");
+    println!("{}", format_calendar(&statuses));
+}
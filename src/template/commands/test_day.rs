@@ -0,0 +1,75 @@
+use std::path::Path;
+use std::process::{self, Command};
+
+use crate::template::example_tests::{format_table, parse_test_output, Outcome};
+use crate::template::{all_days, get_bin_name, Day, Year, ANSI_BOLD, ANSI_RESET};
+
+/// Parse a day or an inclusive range of days, e.g. `5` or `1..10`.
+pub fn parse_days(s: &str) -> Result<Vec<Day>, String> {
+    let (from, to) = s.split_once("..").unwrap_or((s, s));
+
+    let parse = |day: &str| {
+        day.parse::<Day>()
+            .map_err(|_| format!("invalid day `{day}`, expecting a day or a range like `1..10`"))
+    };
+    let (from, to) = (parse(from)?, parse(to)?);
+
+    Ok(all_days().filter(|day| (from..=to).contains(day)).collect())
+}
+
+pub fn handle(year: Year, days: Option<Vec<Day>>) {
+    let days: Vec<Day> = days
+        .unwrap_or_else(|| all_days().collect())
+        .into_iter()
+        .filter(|day| Path::new(&format!("src/bin/{}.rs", get_bin_name(year, *day))).exists())
+        .collect();
+
+    if days.is_empty() {
+        eprintln!("No solutions to test.");
+        process::exit(1);
+    }
+
+    let summaries: Vec<(Day, [Outcome; 2])> = days
+        .into_iter()
+        .map(|day| {
+            println!("Testing day {day}...");
+            (day, test_day(year, day))
+        })
+        .collect();
+
+    println!("\n{}", format_table(&summaries));
+
+    let failed = summaries
+        .iter()
+        .flat_map(|(_, outcomes)| outcomes)
+        .filter(|outcome| outcome.is_failure())
+        .count();
+
+    if failed > 0 {
+        println!("\n{ANSI_BOLD}✘ {failed} part(s) failed.{ANSI_RESET}");
+        process::exit(1);
+    }
+}
+
+fn test_day(year: Year, day: Day) -> [Outcome; 2] {
+    let bin = get_bin_name(year, day);
+
+    // NOTE: backtraces would be printed in place of the panic messages.
+    let output = Command::new("cargo")
+        .args(["test", "--bin", &bin])
+        .env("RUST_BACKTRACE", "0")
+        .output();
+
+    let outcomes = match output {
+        Ok(output) => parse_test_output(&String::from_utf8_lossy(&output.stdout)),
+        Err(e) => {
+            eprintln!("Failed to run cargo: {e}");
+            None
+        }
+    };
+
+    outcomes.unwrap_or_else(|| {
+        eprintln!("Tests of {bin} did not run, see `cargo test --bin {bin}` for details.");
+        [Outcome::BuildFailed, Outcome::BuildFailed]
+    })
+}
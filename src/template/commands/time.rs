@@ -1,16 +1,100 @@
 use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::time::Duration;
 
-use crate::template::run_multi::run_multi;
+use crate::template::compare::{self, print_comparisons, DEFAULT_THRESHOLD};
+use crate::template::history::{self, HistoryEntry, Revision};
+use crate::template::html_report;
+use crate::template::run_multi::{run_multi, Profile};
+use crate::template::runner::RunOptions;
+use crate::template::submissions::now_secs;
 use crate::template::timings::Timings;
-use crate::template::{all_days, readme_benchmarks, Day};
+use crate::template::{all_days, readme, Day, Year, ANSI_BOLD, ANSI_RESET};
 
-pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
-    let stored_timings = Timings::read_from_file();
+pub use crate::template::html_report::DEFAULT_REPORT_DIR;
+
+/// Options of the `time` command.
+#[derive(Clone, Debug)]
+pub struct Options {
+    pub year: Year,
+    /// Bench a single day instead of all days that do not have stored timings yet.
+    pub day: Option<Day>,
+    /// Bench all days.
+    pub run_all: bool,
+    /// Store the timings and update the readme.
+    pub store: bool,
+    pub jobs: usize,
+    pub timeout: Option<Duration>,
+    /// Collect heap statistics instead of benching.
+    pub dhat: bool,
+    /// Compare the timings to the stored timings.
+    pub compare: bool,
+    /// Relative slowdown in percent that counts as a regression when comparing.
+    pub threshold: Option<f64>,
+    /// Only bench this part.
+    pub part: Option<u8>,
+    /// Show the benchmark history instead of benching.
+    pub history: bool,
+    /// Write an HTML report to this directory. Without `store`, the report is rendered from the stored timings without benching.
+    pub report: Option<PathBuf>,
+}
+
+pub fn handle(options: Options) {
+    let Options {
+        year,
+        day,
+        run_all,
+        store,
+        jobs,
+        timeout,
+        dhat,
+        compare,
+        threshold,
+        part,
+        history,
+        report,
+    } = options;
+
+    if history {
+        print_history(year, day);
+        return;
+    }
+
+    if let Some(dir) = report.as_deref().filter(|_| !store) {
+        write_report(year, dir);
+        return;
+    }
+
+    // stored timings cover both parts of a day.
+    if store && part.is_some() {
+        eprintln!(
+            "`--store` can not be combined with `--part`, as stored timings cover both parts."
+        );
+        process::exit(1);
+    }
+
+    let compare_threshold = compare.then(|| threshold.unwrap_or(DEFAULT_THRESHOLD));
+    let stored_timings = match Timings::read_from_file(year) {
+        Ok(timings) => timings,
+        Err(e) if store => {
+            eprintln!("Not storing benchmarks, as the stored timings could not be read: {e}");
+            process::exit(1);
+        }
+        Err(e) => {
+            eprintln!("Failed to read the stored timings: {e}");
+            Timings::default()
+        }
+    };
 
     let days_to_run = day.map_or_else(
         || {
-            if run_all {
+            // heap statistics are not tracked incrementally, profiled runs always cover all days.
+            if run_all || dhat {
                 all_days().collect()
+            } else if compare_threshold.is_some() {
+                // a comparison needs stored timings to compare to.
+                stored_timings.data.iter().map(|t| t.day).collect()
             } else {
                 // when the `--all` flag is not set, filter out days that are fully benched.
                 all_days()
@@ -21,14 +105,68 @@ pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
         |day| HashSet::from([day]),
     );
 
-    let timings = run_multi(&days_to_run, true, true).unwrap();
+    // NOTE: dhat slows down allocations, so profiled runs only collect heap statistics and are not benched.
+    let run_options = RunOptions {
+        is_timed: !dhat,
+        timeout,
+        accept: false,
+        part,
+    };
+
+    let profile = if dhat {
+        Profile::Dhat
+    } else {
+        Profile::Release
+    };
+
+    let run = run_multi(year, &days_to_run, profile, jobs, run_options);
+
+    if !run.wrong_answers.is_empty() {
+        if store {
+            eprintln!("Not storing benchmarks, as some answers do not match their known answers.");
+        }
+        process::exit(1);
+    }
+
+    if !run.failed_days.is_empty() {
+        if store {
+            eprintln!("Not storing benchmarks, as some days could not be run.");
+        }
+        process::exit(1);
+    }
+
+    let has_regressions = compare_threshold.is_some_and(|threshold| {
+        let comparisons = compare::compare(&stored_timings, &run.timings);
+        print_comparisons(&comparisons, threshold);
+        comparisons.iter().any(|c| c.is_regression(threshold))
+    });
 
     if store {
-        let merged_timings = stored_timings.merge(&timings);
-        merged_timings.store_file().unwrap();
+        let merged_timings = if dhat {
+            stored_timings.merge_heap(&run.timings)
+        } else {
+            stored_timings.merge(&run.timings)
+        };
+        merged_timings.store_file(year).unwrap();
+
+        // heap statistics are not part of the history.
+        if !dhat {
+            let revision = Revision::current();
+            let timestamp = now_secs();
+            let entries: Vec<HistoryEntry> = run
+                .timings
+                .data
+                .iter()
+                .map(|timing| HistoryEntry::from_timing(timing, &revision, timestamp))
+                .collect();
+
+            if let Err(e) = history::append(year, &entries) {
+                eprintln!("Failed to append to the benchmark history: {e}");
+            }
+        }
 
         println!();
-        match readme_benchmarks::update(merged_timings) {
+        match readme::update() {
             Ok(()) => {
                 println!("Stored updated benchmarks.");
             }
@@ -36,5 +174,69 @@ pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
                 eprintln!("Failed to store updated benchmarks.");
             }
         }
+
+        if let Some(dir) = &report {
+            write_report(year, dir);
+        }
+    }
+
+    if has_regressions {
+        process::exit(1);
+    }
+}
+
+/// Print the benchmark history of a day, or of every day that has one.
+fn print_history(year: Year, day: Option<Day>) {
+    let entries = match history::read(year) {
+        Ok(entries) => entries,
+        Err(e) => {
+            eprintln!("Failed to read the benchmark history: {e}");
+            process::exit(1);
+        }
+    };
+
+    let days: Vec<Day> = all_days()
+        .filter(|d| day.is_none_or(|day| day == *d))
+        .filter(|d| entries.iter().any(|e| e.day == *d))
+        .collect();
+
+    if days.is_empty() {
+        println!("No benchmark history yet, it is recorded by `cargo time --store`.");
+        return;
+    }
+
+    for (i, day) in days.into_iter().enumerate() {
+        if i > 0 {
+            println!();
+        }
+        println!("{ANSI_BOLD}Day {day}{ANSI_RESET}\n");
+        println!("{}", history::format_history(day, &entries));
+    }
+}
+
+/// Write the HTML report of the stored timings of a year to `dir`.
+fn write_report(year: Year, dir: &Path) {
+    let timings = Timings::read_from_file(year).unwrap_or_else(|e| {
+        eprintln!("Failed to read the stored timings: {e}");
+        process::exit(1);
+    });
+
+    if timings.data.is_empty() {
+        eprintln!("No stored timings for {year} yet, they are stored by `cargo time --store`.");
+        process::exit(1);
+    }
+
+    // the history is optional, the report is rendered without trends if it can not be read.
+    let history = history::read(year).unwrap_or_else(|e| {
+        eprintln!("Failed to read the benchmark history: {e}");
+        vec![]
+    });
+
+    match html_report::write(dir, year, &timings, &history, now_secs()) {
+        Ok(path) => println!("Wrote benchmark report to {}.", path.display()),
+        Err(e) => {
+            eprintln!("Failed to write the benchmark report: {e}");
+            process::exit(1);
+        }
     }
 }
@@ -0,0 +1,118 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+use crate::template::{get_bin_name, get_data_path, Day, Year};
+
+/// How often the watched files are checked for changes.
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Modification times of the watched files, used to detect changes between polls.
+type Snapshot = BTreeMap<PathBuf, SystemTime>;
+
+pub fn handle(year: Year, day: Day, release: bool) {
+    let bin = get_bin_name(year, day);
+    let mut last = snapshot(year, day);
+
+    run(&bin, release);
+
+    loop {
+        thread::sleep(POLL_INTERVAL);
+
+        let current = snapshot(year, day);
+        if current != last {
+            last = current;
+            run(&bin, release);
+        }
+    }
+}
+
+/// Files that affect the solution of a day: the solution, the library and the day's examples and input.
+fn watched_files(year: Year, day: Day) -> Vec<PathBuf> {
+    let mut files = vec![
+        PathBuf::from(format!("src/bin/{}.rs", get_bin_name(year, day))),
+        PathBuf::from("src/lib.rs"),
+    ];
+
+    collect_rust_files(Path::new("src/gameguard"), &mut files);
+
+    for folder in ["examples", "inputs"] {
+        let Ok(entries) = fs::read_dir(get_data_path(year, folder, "")) else {
+            continue;
+        };
+
+        // matches `01.txt` as well as additional examples like `01-2.txt`.
+        files.extend(entries.flatten().map(|entry| entry.path()).filter(|path| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.starts_with(&day.to_string()) && name.ends_with(".txt"))
+        }));
+    }
+
+    files
+}
+
+fn collect_rust_files(dir: &Path, files: &mut Vec<PathBuf>) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+
+    for path in entries.flatten().map(|entry| entry.path()) {
+        if path.is_dir() {
+            collect_rust_files(&path, files);
+        } else if path.extension().is_some_and(|ext| ext == "rs") {
+            files.push(path);
+        }
+    }
+}
+
+fn snapshot(year: Year, day: Day) -> Snapshot {
+    watched_files(year, day)
+        .into_iter()
+        .filter_map(|path| {
+            let modified = fs::metadata(&path).and_then(|m| m.modified()).ok()?;
+            Some((path, modified))
+        })
+        .collect()
+}
+
+/// Run the example tests, then the solution against the real input.
+fn run(bin: &str, release: bool) {
+    // clear the screen and move the cursor to the top left.
+    print!("\x1b[2J\x1b[H");
+    println!("👀 Watching {bin}, press Ctrl-C to stop.\n");
+    io::stdout().flush().unwrap();
+
+    let tests_passed = cargo(&["test", "--quiet", "--bin", bin]);
+
+    let mut run_args = vec!["run", "--quiet", "--bin", bin];
+    if release {
+        run_args.push("--release");
+    }
+    let run_passed = cargo(&run_args);
+
+    println!();
+    println!("{} Example tests", status_icon(tests_passed));
+    println!("{} Real input", status_icon(run_passed));
+}
+
+fn cargo(args: &[&str]) -> bool {
+    Command::new("cargo")
+        .args(args)
+        .stdout(Stdio::inherit())
+        .stderr(Stdio::inherit())
+        .status()
+        .is_ok_and(|status| status.success())
+}
+
+fn status_icon(passed: bool) -> &'static str {
+    if passed {
+        "✅"
+    } else {
+        "❌"
+    }
+}
@@ -0,0 +1,208 @@
+/// Compares benchmark results against stored timings to detect regressions.
+use crate::template::report::{Status, PARSE_PART};
+use crate::template::stats::nanos_to_duration;
+use crate::template::timings::Timings;
+use crate::template::{Day, ANSI_BOLD, ANSI_RESET};
+
+/// Relative slowdown in percent above which a part counts as a regression, unless configured via `--threshold`.
+pub const DEFAULT_THRESHOLD: f64 = 10.0;
+
+/// Change in duration of a single part between stored and new timings.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Comparison {
+    pub day: Day,
+    /// Part of the solution, or [`PARSE_PART`] for the parse phase.
+    pub part: u8,
+    pub before_nanos: f64,
+    /// `None` if the part was timed before, but now failed or timed out.
+    pub after_nanos: Option<f64>,
+    /// Status of the part in `new`.
+    pub status: Status,
+}
+
+impl Comparison {
+    /// Relative change in percent, positive if the part got slower. `None` if the part failed.
+    pub fn change(&self) -> Option<f64> {
+        self.after_nanos
+            .map(|after_nanos| (after_nanos - self.before_nanos) / self.before_nanos * 100.0)
+    }
+
+    /// Parts that failed always count as a regression.
+    pub fn is_regression(&self, threshold: f64) -> bool {
+        self.change().is_none_or(|change| change > threshold)
+    }
+}
+
+/// Compare all parts that are timed in `stored` and were run in `new`.
+/// Parts that were timed in `stored`, but failed or timed out in `new` are compared as well.
+pub fn compare(stored: &Timings, new: &Timings) -> Vec<Comparison> {
+    let mut comparisons = vec![];
+
+    for timing in &new.data {
+        let Some(stored_timing) = stored.data.iter().find(|t| t.day == timing.day) else {
+            continue;
+        };
+
+        for part in [PARSE_PART, 1, 2] {
+            let Some(before_nanos) = stored_timing.part_nanos(part).filter(|n| *n > 0_f64) else {
+                continue;
+            };
+
+            // NOTE: a failed parse phase fails both parts, so it is reported through them.
+            let status = match part {
+                1 => timing.part_1_status,
+                2 => timing.part_2_status,
+                _ => Status::Solved,
+            };
+
+            let after_nanos = timing.part_nanos(part);
+
+            // parts that were not run (e.g. because of `--part`) or did not return an answer are skipped.
+            if after_nanos.is_some()
+                || matches!(status, Status::Panicked | Status::TimedOut | Status::Failed)
+            {
+                comparisons.push(Comparison {
+                    day: timing.day,
+                    part,
+                    before_nanos,
+                    after_nanos,
+                    status,
+                });
+            }
+        }
+    }
+
+    comparisons
+}
+
+/// Print a line per part with its relative change. Regressions beyond the threshold are highlighted.
+pub fn print_comparisons(comparisons: &[Comparison], threshold: f64) {
+    println!("\n{ANSI_BOLD}Compared to stored timings{ANSI_RESET} (threshold: {threshold}%)");
+
+    if comparisons.is_empty() {
+        println!("No stored timings to compare to.");
+        return;
+    }
+
+    for comparison in comparisons {
+        let part = match comparison.part {
+            PARSE_PART => "Parse ".to_string(),
+            part => format!("Part {part}"),
+        };
+
+        let after = match (comparison.after_nanos, comparison.change()) {
+            (Some(after_nanos), Some(change)) => {
+                format!("{:.1?} ({change:+.1}%)", nanos_to_duration(after_nanos))
+            }
+            _ => match comparison.status {
+                Status::Panicked => "panicked".to_string(),
+                Status::TimedOut => "timed out".to_string(),
+                _ => "failed".to_string(),
+            },
+        };
+
+        let line = format!(
+            "Day {} {part}: {:.1?} → {after}",
+            comparison.day,
+            nanos_to_duration(comparison.before_nanos),
+        );
+
+        if comparison.is_regression(threshold) {
+            println!("{ANSI_BOLD}{line} ✘ regression{ANSI_RESET}");
+        } else {
+            println!("{line}");
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use crate::{
+        day,
+        template::{
+            report::{PartReport, Status},
+            timings::{Timing, Timings},
+            Day,
+        },
+    };
+
+    use super::compare;
+
+    fn timings(day_nanos: &[(u8, f64, f64)]) -> Timings {
+        Timings {
+            data: day_nanos
+                .iter()
+                .map(|&(day, part_1, part_2)| {
+                    let day = Day::new(day).unwrap();
+                    let report = |part, duration_nanos| PartReport {
+                        day,
+                        part,
+                        answer: Some("42".into()),
+                        status: Status::Solved,
+                        message: None,
+                        duration_nanos,
+                        samples: 1,
+                        stats: None,
+                        heap: None,
+                        expected: None,
+                    };
+                    Timing::from_reports(day, &[report(1, part_1), report(2, part_2)])
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn compares_overlapping_days() {
+        let stored = timings(&[(1, 1000.0, 2000.0), (2, 1000.0, 1000.0)]);
+        let new = timings(&[(1, 1500.0, 1000.0), (3, 10.0, 10.0)]);
+
+        let comparisons = compare(&stored, &new);
+        assert_eq!(comparisons.len(), 2);
+        assert_eq!(comparisons[0].day, day!(1));
+        assert_eq!(comparisons[0].part, 1);
+        assert_eq!(comparisons[0].change(), Some(50.0));
+        assert_eq!(comparisons[0].is_regression(10.0), true);
+        assert_eq!(comparisons[1].change(), Some(-50.0));
+        assert_eq!(comparisons[1].is_regression(10.0), false);
+    }
+
+    #[test]
+    fn respects_threshold() {
+        let stored = timings(&[(1, 1000.0, 1000.0)]);
+        let new = timings(&[(1, 1200.0, 1000.0)]);
+
+        let comparisons = compare(&stored, &new);
+        assert_eq!(comparisons[0].is_regression(25.0), false);
+        assert_eq!(comparisons[0].is_regression(10.0), true);
+    }
+
+    #[test]
+    fn reports_failed_parts_as_regressions() {
+        let stored = timings(&[(1, 1000.0, 1000.0)]);
+        let mut new = timings(&[(1, 1000.0, 1000.0)]);
+        new.data[0].part_2 = None;
+        new.data[0].part_2_status = Status::TimedOut;
+
+        let comparisons = compare(&stored, &new);
+        assert_eq!(comparisons.len(), 2);
+        assert_eq!(comparisons[1].part, 2);
+        assert_eq!(comparisons[1].after_nanos, None);
+        assert_eq!(comparisons[1].status, Status::TimedOut);
+        assert_eq!(comparisons[1].is_regression(10.0), true);
+    }
+
+    #[test]
+    fn skips_parts_that_were_not_run() {
+        let stored = timings(&[(1, 1000.0, 1000.0)]);
+        let mut new = timings(&[(1, 1000.0, 1000.0)]);
+        new.data[0].part_2 = None;
+        new.data[0].part_2_status = Status::NotSolved;
+
+        let comparisons = compare(&stored, &new);
+        assert_eq!(comparisons.len(), 1);
+        assert_eq!(comparisons[0].part, 1);
+    }
+}
@@ -0,0 +1,255 @@
+//! Summarizes the example tests of solutions, see `cargo test-day`.
+//! Tests are run via `cargo test`, their output is parsed into an outcome per part.
+
+use std::collections::HashMap;
+
+use crate::template::{table, Day};
+
+/// Message of the panic when a data file can not be read, see [`crate::template::read_file`].
+const MISSING_FILE_MESSAGE: &str = "could not open input file";
+
+/// Outcome of the example test of a part.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Outcome {
+    Passed,
+    /// An assertion failed. Values are taken from `assert_eq!(actual, expected)`.
+    Failed {
+        actual: String,
+        expected: String,
+    },
+    /// The test panicked for another reason than a failed assertion.
+    Panicked(String),
+    /// The example file the test reads does not exist.
+    MissingExample,
+    /// The solution does not have a test for the part.
+    NoTest,
+    /// The solution or its tests did not compile.
+    BuildFailed,
+}
+
+impl Outcome {
+    pub fn is_failure(&self) -> bool {
+        matches!(
+            self,
+            Self::Failed { .. } | Self::Panicked(_) | Self::BuildFailed
+        )
+    }
+
+    fn format(&self) -> String {
+        match self {
+            Self::Passed => "✔ passed".into(),
+            Self::Failed { actual, expected } => format!("✘ {actual} (expected {expected})"),
+            Self::Panicked(message) => format!("✘ panicked: {message}"),
+            Self::MissingExample => "– missing example".into(),
+            Self::NoTest => "– no test".into(),
+            Self::BuildFailed => "✘ build failed".into(),
+        }
+    }
+}
+
+/// Part that a test covers, derived from its name, e.g. `tests::test_part_one`.
+fn get_test_part(name: &str) -> Option<u8> {
+    let name = name.rsplit("::").next()?;
+    if name.contains("part_one") || name.contains("part_1") {
+        Some(1)
+    } else if name.contains("part_two") || name.contains("part_2") {
+        Some(2)
+    } else {
+        None
+    }
+}
+
+/// Parse the output of `cargo test` into the outcome of both parts. Returns `None` if no tests were run.
+/// If a part has several tests, the first one that did not pass determines its outcome.
+pub fn parse_test_output(output: &str) -> Option<[Outcome; 2]> {
+    let mut results: Vec<(String, bool)> = vec![];
+    let mut failures: HashMap<String, Vec<&str>> = HashMap::new();
+    let mut current_failure: Option<String> = None;
+
+    for line in output.lines() {
+        if let Some(result) = line.strip_prefix("test ") {
+            if let Some((name, status)) = result.split_once(" ... ") {
+                results.push((name.to_string(), status.trim() == "ok"));
+                continue;
+            }
+        }
+
+        if let Some(name) = line
+            .strip_prefix("---- ")
+            .and_then(|l| l.strip_suffix(" stdout ----"))
+        {
+            current_failure = Some(name.to_string());
+            continue;
+        }
+
+        // the list of failed tests follows the last failure section.
+        if line == "failures:" {
+            current_failure = None;
+        }
+
+        if let Some(name) = &current_failure {
+            failures.entry(name.clone()).or_default().push(line);
+        }
+    }
+
+    if results.is_empty() {
+        return None;
+    }
+
+    let mut outcomes = [Outcome::NoTest, Outcome::NoTest];
+
+    for (name, passed) in results {
+        let Some(part) = get_test_part(&name) else {
+            continue;
+        };
+        let outcome = &mut outcomes[usize::from(part - 1)];
+
+        if passed {
+            if *outcome == Outcome::NoTest {
+                *outcome = Outcome::Passed;
+            }
+        } else if matches!(outcome, Outcome::NoTest | Outcome::Passed) {
+            *outcome = parse_failure(failures.get(&name).map_or(&[], Vec::as_slice));
+        }
+    }
+
+    Some(outcomes)
+}
+
+fn parse_failure(lines: &[&str]) -> Outcome {
+    let value = |prefix: &str| {
+        lines
+            .iter()
+            .find_map(|line| line.trim_start().strip_prefix(prefix))
+            .map(|value| value.trim().to_string())
+    };
+
+    if let (Some(actual), Some(expected)) = (value("left:"), value("right:")) {
+        return Outcome::Failed { actual, expected };
+    }
+
+    // the message follows the `thread '..' panicked at <location>:` line.
+    let message = lines
+        .iter()
+        .skip_while(|line| !line.contains("panicked at"))
+        .nth(1)
+        .map_or_else(
+            || "unknown error".to_string(),
+            |line| line.trim().to_string(),
+        );
+
+    if message.starts_with(MISSING_FILE_MESSAGE) {
+        Outcome::MissingExample
+    } else {
+        Outcome::Panicked(message)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Render a table with the outcome of both parts of every day.
+pub fn format_table(summaries: &[(Day, [Outcome; 2])]) -> String {
+    let rows: Vec<[String; 3]> = summaries
+        .iter()
+        .map(|(day, [one, two])| [day.to_string(), one.format(), two.format()])
+        .collect();
+
+    table::format_table(["Day", "Part 1", "Part 2"], &rows)
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{format_table, parse_test_output, Outcome};
+    use crate::day;
+
+    const OUTPUT: &str = "
+running 3 tests
+test tests::test_part_one ... FAILED
+test tests::test_part_two ... ok
+test tests::test_helper ... FAILED
+
+failures:
+
+---- tests::test_part_one stdout ----
+
+thread 'tests::test_part_one' (123) panicked at src/bin/2024-01.rs:52:9:
+assertion `left == right` failed
+  left: Some(309560)
+ right: Some(11)
+note: run with `RUST_BACKTRACE=1` environment variable to display a backtrace
+
+---- tests::test_helper stdout ----
+
+thread 'tests::test_helper' (124) panicked at src/bin/2024-01.rs:60:9:
+oops
+
+
+failures:
+    tests::test_part_one
+    tests::test_helper
+
+test result: FAILED. 1 passed; 2 failed; 0 ignored; 0 measured; 0 filtered out; finished in 0.00s
+";
+
+    #[test]
+    fn parses_test_output() {
+        assert_eq!(
+            parse_test_output(OUTPUT),
+            Some([
+                Outcome::Failed {
+                    actual: "Some(309560)".into(),
+                    expected: "Some(11)".into()
+                },
+                Outcome::Passed
+            ])
+        );
+
+        let missing = "test tests::test_part_two ... FAILED
+---- tests::test_part_two stdout ----
+thread 'tests::test_part_two' (1) panicked at src/template/mod.rs:49:38:
+could not open input file: Os { code: 2 }
+";
+        assert_eq!(
+            parse_test_output(missing),
+            Some([Outcome::NoTest, Outcome::MissingExample])
+        );
+
+        let panicked = OUTPUT.replace("test_helper", "test_part_two_example_2");
+        assert_eq!(
+            parse_test_output(&panicked).map(|[_, two]| two),
+            Some(Outcome::Panicked("oops".into()))
+        );
+
+        assert_eq!(parse_test_output("error[E0425]: cannot find value"), None);
+    }
+
+    #[test]
+    fn formats_table() {
+        let table = format_table(&[
+            (day!(1), [Outcome::Passed, Outcome::MissingExample]),
+            (
+                day!(2),
+                [
+                    Outcome::Failed {
+                        actual: "1".into(),
+                        expected: "2".into(),
+                    },
+                    Outcome::BuildFailed,
+                ],
+            ),
+        ]);
+
+        assert_eq!(
+            table,
+            [
+                "| Day | Part 1           | Part 2            |",
+                "|-----|------------------|-------------------|",
+                "| 01  | ✔ passed         | – missing example |",
+                "| 02  | ✘ 1 (expected 2) | ✘ build failed    |",
+            ]
+            .join("\n")
+        );
+    }
+}
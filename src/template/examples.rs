@@ -0,0 +1,128 @@
+//! Extracts example inputs and their answers from puzzle descriptions, see `cargo examples`.
+//! Works on the markdown that `download` and `read` store in `data/<year>/puzzles`.
+
+/// Heading that starts the second part of a puzzle description.
+const PART_TWO_HEADING: &str = "## --- Part Two ---";
+
+/// A code block of a puzzle description.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CodeBlock {
+    /// Part of the puzzle description the block appears in.
+    pub part: u8,
+    /// Contents of the block, with a trailing newline like puzzle inputs.
+    pub content: String,
+}
+
+fn split_parts(markdown: &str) -> [&str; 2] {
+    match markdown.find(PART_TWO_HEADING) {
+        Some(index) => [&markdown[..index], &markdown[index..]],
+        None => [markdown, ""],
+    }
+}
+
+/// Collect the code blocks of a puzzle description in order of appearance. Repeated blocks are only returned once.
+pub fn code_blocks(markdown: &str) -> Vec<CodeBlock> {
+    let mut blocks: Vec<CodeBlock> = vec![];
+
+    for (part, text) in (1..).zip(split_parts(markdown)) {
+        let mut current: Option<Vec<&str>> = None;
+
+        for line in text.lines() {
+            match (line.trim_end() == "```", current.as_mut()) {
+                (true, None) => current = Some(vec![]),
+                (true, Some(lines)) => {
+                    let content = format!("{}\n", lines.join("\n"));
+                    if !lines.is_empty() && !blocks.iter().any(|b| b.content == content) {
+                        blocks.push(CodeBlock { part, content });
+                    }
+                    current = None;
+                }
+                (false, Some(lines)) => lines.push(line),
+                (false, None) => {}
+            }
+        }
+    }
+
+    blocks
+}
+
+/// Find the answers to the examples of both parts. The answer is the last highlighted code of a part, e.g. *`11`*.
+pub fn highlighted_answers(markdown: &str) -> [Option<String>; 2] {
+    split_parts(markdown).map(|text| {
+        let mut answer = None;
+        let mut rest = text;
+
+        while let Some(start) = rest.find("*`") {
+            rest = &rest[start + 2..];
+            let Some(end) = rest.find("`*") else {
+                break;
+            };
+            answer = Some(rest[..end].to_string());
+            rest = &rest[end + 2..];
+        }
+
+        answer
+    })
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{code_blocks, highlighted_answers, CodeBlock};
+
+    const PUZZLE: &str = "## --- Day 1: Test ---
+
+For example:
+
+```
+3   4
+4   3
+```
+
+Pair up *`3`* and *`3`*, then *`4`* and *`4`*. In total, the answer is *`11`*.
+
+```
+3   4
+4   3
+```
+
+## --- Part Two ---
+
+Now with a new example:
+
+```
+xmul(2,4)
+```
+
+The answer is `not highlighted` and *`31`*.";
+
+    #[test]
+    fn collects_code_blocks() {
+        assert_eq!(
+            code_blocks(PUZZLE),
+            vec![
+                CodeBlock {
+                    part: 1,
+                    content: "3   4\n4   3\n".into()
+                },
+                CodeBlock {
+                    part: 2,
+                    content: "xmul(2,4)\n".into()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn finds_highlighted_answers() {
+        assert_eq!(
+            highlighted_answers(PUZZLE),
+            [Some("11".into()), Some("31".into())]
+        );
+
+        let part_one = PUZZLE.split("## --- Part Two ---").next().unwrap();
+        assert_eq!(highlighted_answers(part_one), [Some("11".into()), None]);
+        assert_eq!(highlighted_answers("no answers"), [None, None]);
+    }
+}
@@ -0,0 +1,360 @@
+/// Append-only history of stored benchmarks, see `cargo time --history`.
+/// Every `cargo time --store` appends a record per benched day, tagged with the git commit it was run on.
+use std::collections::HashMap;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::process::Command;
+use std::str::FromStr;
+
+use tinyjson::JsonValue;
+
+use crate::template::report::PARSE_PART;
+use crate::template::stats::nanos_to_duration;
+use crate::template::table::format_table;
+use crate::template::timings::Timing;
+use crate::template::{Day, Year};
+
+fn get_history_path(year: Year) -> String {
+    format!("./data/{year}/timings_history.jsonl")
+}
+
+/// The git commit that the working tree is at.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Revision {
+    /// Abbreviated hash of the commit, `None` outside of a git repository.
+    pub commit: Option<String>,
+    /// The working tree has uncommitted changes.
+    pub dirty: bool,
+}
+
+impl Revision {
+    pub fn current() -> Self {
+        let git = |args: &[&str]| {
+            Command::new("git")
+                .args(args)
+                .output()
+                .ok()
+                .filter(|output| output.status.success())
+                .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+        };
+
+        match git(&["rev-parse", "--short", "HEAD"]) {
+            Some(commit) => Revision {
+                commit: Some(commit),
+                dirty: git(&["status", "--porcelain"]).is_some_and(|status| !status.is_empty()),
+            },
+            None => Revision::default(),
+        }
+    }
+}
+
+/// Stored timings of a day at a point in time.
+#[derive(Clone, Debug, PartialEq)]
+pub struct HistoryEntry {
+    pub revision: Revision,
+    /// Unix timestamp in seconds.
+    pub timestamp: u64,
+    pub day: Day,
+    pub parse_nanos: Option<f64>,
+    pub part_1_nanos: Option<f64>,
+    pub part_2_nanos: Option<f64>,
+}
+
+impl HistoryEntry {
+    pub fn from_timing(timing: &Timing, revision: &Revision, timestamp: u64) -> Self {
+        HistoryEntry {
+            revision: revision.clone(),
+            timestamp,
+            day: timing.day,
+            parse_nanos: timing.part_nanos(PARSE_PART),
+            part_1_nanos: timing.part_nanos(1),
+            part_2_nanos: timing.part_nanos(2),
+        }
+    }
+
+    fn part_nanos(&self, part: u8) -> Option<f64> {
+        match part {
+            PARSE_PART => self.parse_nanos,
+            1 => self.part_1_nanos,
+            2 => self.part_2_nanos,
+            _ => None,
+        }
+    }
+
+    /// Combined duration of the parse phase and both parts in nanoseconds.
+    pub fn total_nanos(&self) -> f64 {
+        [self.parse_nanos, self.part_1_nanos, self.part_2_nanos]
+            .into_iter()
+            .flatten()
+            .sum()
+    }
+}
+
+/// Append entries to the history of a year.
+pub fn append(year: Year, entries: &[HistoryEntry]) -> Result<(), io::Error> {
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(get_history_path(year))?;
+
+    for entry in entries {
+        let json = JsonValue::from(entry)
+            .stringify()
+            .map_err(io::Error::other)?;
+        writeln!(file, "{json}")?;
+    }
+
+    Ok(())
+}
+
+/// Read the history of a year, oldest entry first. A missing file is treated as an empty history.
+pub fn read(year: Year) -> Result<Vec<HistoryEntry>, String> {
+    let Ok(contents) = fs::read_to_string(get_history_path(year)) else {
+        return Ok(vec![]);
+    };
+
+    contents
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let json = JsonValue::from_str(line).or(Err("not a valid JSON record."))?;
+            HistoryEntry::try_from(&json)
+        })
+        .collect()
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Render the history of a day as a table, with the change of every part relative to the previous entry.
+pub fn format_history(day: Day, entries: &[HistoryEntry]) -> String {
+    let entries: Vec<&HistoryEntry> = entries.iter().filter(|e| e.day == day).collect();
+
+    let rows: Vec<[String; 5]> = entries
+        .iter()
+        .enumerate()
+        .map(|(i, entry)| {
+            let previous = i.checked_sub(1).map(|i| entries[i]);
+
+            let format_part = |part: u8| {
+                let Some(nanos) = entry.part_nanos(part) else {
+                    return "-".to_string();
+                };
+                let duration = format!("{:.1?}", nanos_to_duration(nanos));
+
+                match previous.and_then(|p| p.part_nanos(part)) {
+                    Some(before) if before > 0_f64 => {
+                        format!("{duration} ({:+.1}%)", (nanos - before) / before * 100.0)
+                    }
+                    _ => duration,
+                }
+            };
+
+            let commit = match &entry.revision {
+                Revision {
+                    commit: Some(commit),
+                    dirty: true,
+                } => format!("{commit}-dirty"),
+                Revision {
+                    commit: Some(commit),
+                    dirty: false,
+                } => commit.clone(),
+                Revision { commit: None, .. } => "unknown".into(),
+            };
+
+            [
+                commit,
+                format_timestamp(entry.timestamp),
+                format_part(PARSE_PART),
+                format_part(1),
+                format_part(2),
+            ]
+        })
+        .collect();
+
+    format_table(["Commit", "Date", "Parse", "Part 1", "Part 2"], &rows)
+}
+
+/// Format a unix timestamp as a UTC date and time, e.g. `2024-12-01 05:00`.
+pub fn format_timestamp(secs: u64) -> String {
+    let days = secs / 86_400;
+    let (hours, minutes) = (secs % 86_400 / 3600, secs % 3600 / 60);
+
+    // convert days since the epoch to a civil date, see http://howardhinnant.github.io/date_algorithms.html
+    let z = days + 719_468;
+    let era = z / 146_097;
+    let doe = z % 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + u64::from(month <= 2);
+
+    format!("{year}-{month:02}-{day:02} {hours:02}:{minutes:02}")
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&HistoryEntry> for JsonValue {
+    fn from(value: &HistoryEntry) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "commit".into(),
+            match &value.revision.commit {
+                Some(x) => JsonValue::String(x.clone()),
+                None => JsonValue::Null,
+            },
+        );
+        map.insert("dirty".into(), JsonValue::Boolean(value.revision.dirty));
+
+        #[allow(clippy::cast_precision_loss)]
+        map.insert(
+            "timestamp".into(),
+            JsonValue::Number(value.timestamp as f64),
+        );
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+
+        for (key, nanos) in [
+            ("parse_nanos", value.parse_nanos),
+            ("part_1_nanos", value.part_1_nanos),
+            ("part_2_nanos", value.part_2_nanos),
+        ] {
+            map.insert(
+                key.into(),
+                match nanos {
+                    Some(x) => JsonValue::Number(x),
+                    None => JsonValue::Null,
+                },
+            );
+        }
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for HistoryEntry {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected history entry to be a JSON object.")?;
+
+        let commit = json
+            .get("commit")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected entry.commit to be null or string.")?
+            .cloned();
+
+        let dirty = *json
+            .get("dirty")
+            .and_then(|v| v.get::<bool>())
+            .ok_or("Expected entry.dirty to be a boolean.")?;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let timestamp = json
+            .get("timestamp")
+            .and_then(|v| v.get::<f64>())
+            .map(|x| *x as u64)
+            .ok_or("Expected entry.timestamp to be a number.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected entry.day to be a Day struct.")?;
+
+        let nanos = |key: &str| match json.get(key) {
+            Some(v) if !v.is_null() => v
+                .get::<f64>()
+                .copied()
+                .map(Some)
+                .ok_or(format!("Expected entry.{key} to be null or number.")),
+            _ => Ok(None),
+        };
+
+        Ok(HistoryEntry {
+            revision: Revision { commit, dirty },
+            timestamp,
+            day,
+            parse_nanos: nanos("parse_nanos")?,
+            part_1_nanos: nanos("part_1_nanos")?,
+            part_2_nanos: nanos("part_2_nanos")?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{format_history, format_timestamp, HistoryEntry, Revision};
+    use crate::day;
+    use tinyjson::JsonValue;
+
+    fn get_entries() -> Vec<HistoryEntry> {
+        vec![
+            HistoryEntry {
+                revision: Revision {
+                    commit: Some("abc1234".into()),
+                    dirty: false,
+                },
+                timestamp: 1_733_029_200,
+                day: day!(1),
+                parse_nanos: None,
+                part_1_nanos: Some(2_000_000.0),
+                part_2_nanos: None,
+            },
+            HistoryEntry {
+                revision: Revision {
+                    commit: Some("def5678".into()),
+                    dirty: true,
+                },
+                timestamp: 1_733_115_660,
+                day: day!(1),
+                parse_nanos: None,
+                part_1_nanos: Some(1_500_000.0),
+                part_2_nanos: Some(10_000.0),
+            },
+            HistoryEntry {
+                revision: Revision::default(),
+                timestamp: 1_733_115_660,
+                day: day!(2),
+                parse_nanos: Some(1_000.0),
+                part_1_nanos: None,
+                part_2_nanos: None,
+            },
+        ]
+    }
+
+    #[test]
+    fn formats_timestamps() {
+        assert_eq!(format_timestamp(0), "1970-01-01 00:00");
+        assert_eq!(format_timestamp(1_733_029_200), "2024-12-01 05:00");
+        assert_eq!(format_timestamp(1_709_164_800), "2024-02-29 00:00");
+    }
+
+    #[test]
+    fn formats_history() {
+        assert_eq!(
+            format_history(day!(1), &get_entries()),
+            [
+                "| Commit        | Date             | Parse | Part 1         | Part 2 |",
+                "|---------------|------------------|-------|----------------|--------|",
+                "| abc1234       | 2024-12-01 05:00 | -     | 2.0ms          | -      |",
+                "| def5678-dirty | 2024-12-02 05:01 | -     | 1.5ms (-25.0%) | 10.0µs |",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn serializes_entries() {
+        for entry in get_entries() {
+            let json = JsonValue::from(&entry);
+            assert_eq!(HistoryEntry::try_from(&json), Ok(entry));
+        }
+    }
+}
@@ -0,0 +1,425 @@
+//! Renders stored benchmarks to a self-contained HTML page, see `cargo time --report`.
+//! The page contains an SVG bar chart of the times of every part and, if there is a benchmark history, a trend line per day.
+
+use std::fmt::Write as _;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::template::history::{format_timestamp, HistoryEntry};
+use crate::template::report::PARSE_PART;
+use crate::template::stats::nanos_to_duration;
+use crate::template::timings::{Timing, Timings};
+use crate::template::{Day, Year};
+
+/// Directory the report is written to if none is passed, next to the other assets of the readme.
+pub const DEFAULT_REPORT_DIR: &str = ".assets";
+
+const BACKGROUND: &str = "#0f0f23";
+const FOREGROUND: &str = "#cccccc";
+const GRID: &str = "#333340";
+
+/// Label and color of the bars of every part, in the order they are drawn.
+const SERIES: [(u8, &str, &str); 3] = [
+    (PARSE_PART, "Parse", "#009900"),
+    (1, "Part 1", "#9999cc"),
+    (2, "Part 2", "#ffff66"),
+];
+
+const LABEL_WIDTH: usize = 70;
+const CHART_WIDTH: usize = 560;
+/// Space to the right of the bars for the label of the slowest part.
+const VALUE_WIDTH: usize = 80;
+const LEGEND_HEIGHT: usize = 30;
+const AXIS_HEIGHT: usize = 24;
+const BAR_HEIGHT: usize = 8;
+const ROW_GAP: usize = 10;
+
+const TREND_WIDTH: usize = 160;
+const TREND_HEIGHT: usize = 24;
+
+/// Paths of the files of a report.
+pub fn get_report_paths(dir: &Path, year: Year) -> (PathBuf, PathBuf) {
+    (
+        dir.join(format!("benchmarks-{year}.html")),
+        dir.join(format!("benchmarks-{year}.svg")),
+    )
+}
+
+/// Write the report of a year to `dir`: the HTML page and its bar chart as a standalone SVG, e.g. to embed it into the readme.
+pub fn write(
+    dir: &Path,
+    year: Year,
+    timings: &Timings,
+    history: &[HistoryEntry],
+    generated_at: u64,
+) -> Result<PathBuf, io::Error> {
+    fs::create_dir_all(dir)?;
+
+    let (html_path, svg_path) = get_report_paths(dir, year);
+    fs::write(
+        &html_path,
+        render_page(year, timings, history, generated_at),
+    )?;
+    fs::write(svg_path, render_chart(timings))?;
+
+    Ok(html_path)
+}
+
+/* -------------------------------------------------------------------------- */
+
+fn format_nanos(nanos: f64) -> String {
+    format!("{:.1?}", nanos_to_duration(nanos))
+}
+
+/// Power of ten range of the parts, used as domain of the logarithmic scale of the chart.
+/// Times of different days are often orders of magnitude apart, so a linear scale would hide most bars.
+#[allow(clippy::cast_possible_truncation)]
+fn get_scale_domain(timings: &Timings) -> (i32, i32) {
+    let (min, max) = timings
+        .data
+        .iter()
+        .flat_map(|timing| SERIES.map(|(part, _, _)| timing.part_nanos(part)))
+        .flatten()
+        // sub-nanosecond parts are drawn at the lower end of the scale.
+        .map(|nanos| nanos.max(1_f64))
+        .fold((f64::MAX, f64::MIN), |(min, max), n| {
+            (min.min(n), max.max(n))
+        });
+
+    if min > max {
+        return (0, 1);
+    }
+
+    let low = min.log10().floor() as i32;
+    let high = (max.log10().ceil() as i32).max(low + 1);
+    (low, high)
+}
+
+/// Width of the bar of a duration on a logarithmic scale. Durations at the lower end of the scale get a minimal bar.
+#[allow(clippy::cast_precision_loss)]
+fn scale(nanos: f64, (low, high): (i32, i32)) -> f64 {
+    let ratio = (nanos.max(1_f64).log10() - f64::from(low)) / f64::from(high - low);
+    (ratio.clamp(0_f64, 1_f64) * CHART_WIDTH as f64).max(1_f64)
+}
+
+/// Render a horizontal bar chart with a group of bars per day, one for every timed part.
+#[allow(clippy::cast_precision_loss, clippy::cast_sign_loss)]
+pub fn render_chart(timings: &Timings) -> String {
+    let domain = get_scale_domain(timings);
+    let bars = SERIES.len();
+    let row_height = bars * BAR_HEIGHT + ROW_GAP;
+
+    let width = LABEL_WIDTH + CHART_WIDTH + VALUE_WIDTH;
+    let height = LEGEND_HEIGHT + timings.data.len() * row_height + AXIS_HEIGHT;
+    let axis_y = LEGEND_HEIGHT + timings.data.len() * row_height;
+
+    let mut svg = String::new();
+
+    let _ = writeln!(
+        svg,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" viewBox="0 0 {width} {height}" font-family="monospace" font-size="11">"#
+    );
+    let _ = writeln!(
+        svg,
+        r#"  <rect width="{width}" height="{height}" fill="{BACKGROUND}"/>"#
+    );
+
+    for (index, (_, label, color)) in SERIES.iter().enumerate() {
+        let x = LABEL_WIDTH + index * 90;
+        let _ = writeln!(
+            svg,
+            r#"  <rect x="{x}" y="10" width="10" height="10" fill="{color}"/><text x="{}" y="19" fill="{FOREGROUND}">{label}</text>"#,
+            x + 14
+        );
+    }
+
+    // a grid line per power of ten.
+    for exponent in domain.0..=domain.1 {
+        let x = LABEL_WIDTH as f64 + scale(10_f64.powi(exponent), domain);
+        let label = format!("{:?}", Duration::from_nanos(10_u64.pow(exponent as u32)));
+        let _ = writeln!(
+            svg,
+            r#"  <line x1="{x:.1}" y1="{LEGEND_HEIGHT}" x2="{x:.1}" y2="{axis_y}" stroke="{GRID}"/><text x="{x:.1}" y="{}" fill="{FOREGROUND}" text-anchor="middle">{label}</text>"#,
+            axis_y + 16
+        );
+    }
+
+    for (row, timing) in timings.data.iter().enumerate() {
+        let y = LEGEND_HEIGHT + row * row_height;
+        let _ = writeln!(
+            svg,
+            r#"  <text x="{}" y="{}" fill="{FOREGROUND}" text-anchor="end">Day {}</text>"#,
+            LABEL_WIDTH - 10,
+            y + bars * BAR_HEIGHT / 2 + 4,
+            timing.day
+        );
+
+        for (index, (part, label, color)) in SERIES.iter().enumerate() {
+            let Some(nanos) = timing.part_nanos(*part) else {
+                continue;
+            };
+
+            let bar_y = y + index * BAR_HEIGHT;
+            let bar_width = scale(nanos, domain);
+            let duration = format_nanos(nanos);
+
+            let _ = writeln!(
+                svg,
+                r#"  <rect x="{LABEL_WIDTH}" y="{bar_y}" width="{bar_width:.1}" height="{}" fill="{color}"><title>Day {} {label}: {duration}</title></rect><text x="{:.1}" y="{}" fill="{FOREGROUND}" font-size="8">{duration}</text>"#,
+                BAR_HEIGHT - 1,
+                timing.day,
+                LABEL_WIDTH as f64 + bar_width + 4_f64,
+                bar_y + BAR_HEIGHT - 1,
+            );
+        }
+    }
+
+    svg.push_str("</svg>\n");
+    svg
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Render the total time of a day over its history as a line. Returns `None` if there are less than two entries.
+#[allow(clippy::cast_precision_loss)]
+fn render_trend(entries: &[&HistoryEntry]) -> Option<String> {
+    if entries.len() < 2 {
+        return None;
+    }
+
+    let totals: Vec<f64> = entries.iter().map(|e| e.total_nanos()).collect();
+    let min = totals.iter().copied().fold(f64::MAX, f64::min);
+    let max = totals.iter().copied().fold(f64::MIN, f64::max);
+
+    let (width, height) = (TREND_WIDTH as f64, TREND_HEIGHT as f64);
+
+    let points: Vec<String> = totals
+        .iter()
+        .enumerate()
+        .map(|(i, total)| {
+            let x = i as f64 / (totals.len() - 1) as f64 * width;
+            // a flat line is drawn in the middle, the padding keeps the stroke visible at the edges.
+            let y = if max > min {
+                2_f64 + (1_f64 - (total - min) / (max - min)) * (height - 4_f64)
+            } else {
+                height / 2_f64
+            };
+            format!("{x:.1},{y:.1}")
+        })
+        .collect();
+
+    Some(format!(
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{TREND_WIDTH}" height="{TREND_HEIGHT}"><polyline points="{}" fill="none" stroke="{}" stroke-width="1.5"/></svg>"#,
+        points.join(" "),
+        SERIES[2].2
+    ))
+}
+
+fn format_change(entries: &[&HistoryEntry]) -> String {
+    match (entries.first(), entries.last()) {
+        (Some(first), Some(last)) if entries.len() > 1 && first.total_nanos() > 0_f64 => {
+            let change = (last.total_nanos() - first.total_nanos()) / first.total_nanos() * 100.0;
+            format!("{change:+.1}% over {} runs", entries.len())
+        }
+        _ => "-".into(),
+    }
+}
+
+fn format_cell(timing: &Timing, part: u8) -> String {
+    timing
+        .part_nanos(part)
+        .map_or_else(|| "-".into(), format_nanos)
+}
+
+/// Render the page of a year. The trend column is only rendered if there is a history.
+pub fn render_page(
+    year: Year,
+    timings: &Timings,
+    history: &[HistoryEntry],
+    generated_at: u64,
+) -> String {
+    let has_history = !history.is_empty();
+    let history_of = |day: Day| -> Vec<&HistoryEntry> {
+        history.iter().filter(|entry| entry.day == day).collect()
+    };
+
+    let mut rows = String::new();
+    for timing in &timings.data {
+        let _ = write!(
+            rows,
+            "      <tr><td>Day {}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td>",
+            timing.day,
+            format_cell(timing, PARSE_PART),
+            format_cell(timing, 1),
+            format_cell(timing, 2),
+            format_nanos(timing.total_nanos()),
+        );
+
+        if has_history {
+            let entries = history_of(timing.day);
+            let _ = write!(
+                rows,
+                "<td>{}</td><td>{}</td>",
+                render_trend(&entries).unwrap_or_default(),
+                format_change(&entries)
+            );
+        }
+
+        rows.push_str("</tr>\n");
+    }
+
+    let trend_headers = if has_history {
+        "<th>Trend</th><th>Change</th>"
+    } else {
+        ""
+    };
+
+    format!(
+        r#"<!DOCTYPE html>
+<html lang="en">
+  <head>
+    <meta charset="utf-8">
+    <title>Advent of Code {year} Benchmarks</title>
+    <style>
+      body {{ background: {BACKGROUND}; color: {FOREGROUND}; font-family: monospace; margin: 2em; }}
+      h1 {{ color: #00cc00; font-weight: normal; }}
+      table {{ border-collapse: collapse; margin-top: 2em; }}
+      th, td {{ padding: 0.25em 1em; text-align: right; border-bottom: 1px solid {GRID}; }}
+      td svg {{ vertical-align: middle; }}
+      footer {{ margin-top: 2em; color: #666666; }}
+    </style>
+  </head>
+  <body>
+    <h1>Advent of Code {year} Benchmarks</h1>
+{chart}    <table>
+      <tr><th>Day</th><th>Parse</th><th>Part 1</th><th>Part 2</th><th>Total</th>{trend_headers}</tr>
+{rows}    </table>
+    <p><strong>Total: {total:.2}ms</strong></p>
+    <footer>Generated at {generated} UTC by <code>cargo time --report</code>.</footer>
+  </body>
+</html>
+"#,
+        chart = render_chart(timings),
+        total = timings.total_millis(),
+        generated = format_timestamp(generated_at),
+    )
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{get_scale_domain, render_chart, render_page, render_trend, scale};
+    use crate::template::history::{HistoryEntry, Revision};
+    use crate::template::report::Status;
+    use crate::template::timings::{PartTiming, Timing, Timings};
+    use crate::{day, year};
+
+    fn part_timing(nanos: f64) -> Option<PartTiming> {
+        Some(PartTiming {
+            nanos,
+            samples: 1,
+            stats: None,
+        })
+    }
+
+    fn get_mock_timings() -> Timings {
+        Timings {
+            data: vec![
+                Timing {
+                    day: day!(1),
+                    parse: None,
+                    part_1: part_timing(1_500.0),
+                    part_2: part_timing(2_000_000.0),
+                    part_1_heap: None,
+                    part_2_heap: None,
+                    parse_heap: None,
+                    part_1_status: Status::Solved,
+                    part_2_status: Status::Solved,
+                },
+                Timing {
+                    day: day!(2),
+                    parse: part_timing(800.0),
+                    part_1: part_timing(30_000.0),
+                    part_2: None,
+                    part_1_heap: None,
+                    part_2_heap: None,
+                    parse_heap: None,
+                    part_1_status: Status::Solved,
+                    part_2_status: Status::NotSolved,
+                },
+            ],
+        }
+    }
+
+    fn entry(timestamp: u64, part_1_nanos: f64) -> HistoryEntry {
+        HistoryEntry {
+            revision: Revision::default(),
+            timestamp,
+            day: day!(1),
+            parse_nanos: None,
+            part_1_nanos: Some(part_1_nanos),
+            part_2_nanos: None,
+        }
+    }
+
+    #[test]
+    fn scales_logarithmically() {
+        let domain = get_scale_domain(&get_mock_timings());
+        assert_eq!(domain, (2, 7));
+        assert_eq!(scale(100.0, domain), 1.0);
+        assert_eq!(scale(10_000.0, domain), 224.0);
+        assert_eq!(scale(10_000_000.0, domain), 560.0);
+        assert_eq!(get_scale_domain(&Timings::default()), (0, 1));
+    }
+
+    #[test]
+    fn renders_chart() {
+        let svg = render_chart(&get_mock_timings());
+        assert_eq!(
+            svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\""),
+            true
+        );
+        assert_eq!(svg.ends_with("</svg>\n"), true);
+        // a bar per timed part.
+        assert_eq!(svg.matches("<title>").count(), 4);
+        assert_eq!(svg.contains("<title>Day 01 Part 2: 2.0ms</title>"), true);
+        assert_eq!(svg.contains("<title>Day 02 Parse: 800.0ns</title>"), true);
+        assert_eq!(svg.contains(">100ns</text>"), true);
+        assert_eq!(svg.contains(">10ms</text>"), true);
+    }
+
+    #[test]
+    fn renders_trends() {
+        let entries = [entry(0, 2_000.0), entry(1, 1_000.0), entry(2, 1_000.0)];
+        let entries: Vec<&HistoryEntry> = entries.iter().collect();
+        assert_eq!(
+            render_trend(&entries)
+                .unwrap()
+                .contains(r#"points="0.0,2.0 80.0,22.0 160.0,22.0""#),
+            true
+        );
+        assert_eq!(render_trend(&entries[..1]), None);
+    }
+
+    #[test]
+    fn renders_page() {
+        let page = render_page(year!(2024), &get_mock_timings(), &[], 0);
+        assert_eq!(page.contains("<th>Trend</th>"), false);
+        assert_eq!(
+            page.contains(
+                "<tr><td>Day 02</td><td>800.0ns</td><td>30.0µs</td><td>-</td><td>30.8µs</td></tr>"
+            ),
+            true
+        );
+        assert_eq!(page.contains("Generated at 1970-01-01 00:00 UTC"), true);
+
+        let history = [entry(0, 2_000.0), entry(1, 1_000.0)];
+        let page = render_page(year!(2024), &get_mock_timings(), &history, 0);
+        assert_eq!(page.contains("<th>Trend</th><th>Change</th>"), true);
+        assert_eq!(page.contains("<td>-50.0% over 2 runs</td>"), true);
+        assert_eq!(page.contains("<td></td><td>-</td></tr>"), true);
+    }
+}
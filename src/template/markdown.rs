@@ -0,0 +1,238 @@
+//! Converts puzzle pages of the advent of code website to markdown.
+//! Only covers the handful of tags that are used in puzzle descriptions.
+
+/// Convert the puzzle descriptions (`<article>` elements) of a puzzle page to markdown, followed by the answers of solved parts.
+pub fn puzzle_to_markdown(html: &str) -> String {
+    let mut parts = vec![];
+    let mut rest = html;
+
+    while let Some(start) = rest.find("<article") {
+        let article = &rest[start..];
+        let Some(content_start) = article.find('>').map(|i| i + 1) else {
+            break;
+        };
+        let content_end = article.find("</article>").unwrap_or(article.len());
+        parts.push(html_to_markdown(&article[content_start..content_end]));
+        rest = &article[content_end..];
+
+        // the answer to a solved part follows its description, outside of the article.
+        let between = &rest[..rest.find("<article").unwrap_or(rest.len())];
+        if let Some(answer) = find_answer_paragraph(between) {
+            parts.push(html_to_markdown(answer));
+        }
+    }
+
+    parts.join("\n\n")
+}
+
+/// Find the paragraph that shows the answer to a solved part, see `readme_stars`.
+fn find_answer_paragraph(html: &str) -> Option<&str> {
+    let start = html.find("<p>Your puzzle answer was")?;
+    let end = html[start..]
+        .find("</p>")
+        .map_or(html.len(), |i| start + i + 4);
+    Some(&html[start..end])
+}
+
+/// Convert an HTML fragment to markdown.
+pub fn html_to_markdown(html: &str) -> String {
+    let mut out = String::new();
+    let mut code = String::new();
+    let mut in_pre = false;
+    let mut in_code = false;
+    let mut code_has_em = false;
+    let mut links: Vec<String> = vec![];
+
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        let Some(tag_start) = rest.find('<') else {
+            push_text(&mut out, &mut code, in_code && !in_pre, rest);
+            break;
+        };
+
+        push_text(&mut out, &mut code, in_code && !in_pre, &rest[..tag_start]);
+
+        let Some(tag_end) = rest[tag_start..].find('>').map(|i| tag_start + i) else {
+            break;
+        };
+        let tag = &rest[tag_start + 1..tag_end];
+        rest = &rest[tag_end + 1..];
+
+        let is_closing = tag.starts_with('/');
+        let name = tag
+            .trim_start_matches('/')
+            .split(|c: char| c.is_whitespace() || c == '/')
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+
+        match (name.as_str(), is_closing) {
+            ("pre", false) => {
+                in_pre = true;
+                out.push_str("```\n");
+            }
+            ("pre", true) => {
+                in_pre = false;
+                if !out.ends_with('\n') {
+                    out.push('\n');
+                }
+                out.push_str("```\n\n");
+            }
+            // code blocks are rendered as-is, highlights within them are lost.
+            ("code" | "em", _) if in_pre => {}
+            ("code", false) => {
+                in_code = true;
+                code_has_em = false;
+                code.clear();
+            }
+            ("code", true) => {
+                in_code = false;
+                // highlighted code, e.g. the answer to an example.
+                if code_has_em {
+                    out.push_str(&format!("*`{code}`*"));
+                } else {
+                    out.push_str(&format!("`{code}`"));
+                }
+            }
+            ("em", _) if in_code => code_has_em = true,
+            ("em", _) => out.push('*'),
+            ("a", false) => {
+                links.push(get_attribute(tag, "href").unwrap_or_default());
+                out.push('[');
+            }
+            ("a", true) => {
+                let href = links.pop().unwrap_or_default();
+                out.push_str(&format!("]({href})"));
+            }
+            ("h2", false) => out.push_str("## "),
+            ("h2" | "p", true) => out.push_str("\n\n"),
+            ("li", false) => out.push_str("- "),
+            ("li" | "br", _) => out.push('\n'),
+            ("ul", true) => out.push('\n'),
+            _ => {}
+        }
+    }
+
+    out.trim_end().to_string()
+}
+
+fn push_text(out: &mut String, code: &mut String, in_code: bool, text: &str) {
+    if text.is_empty() {
+        return;
+    }
+
+    let text = decode_entities(text);
+    if in_code {
+        code.push_str(&text);
+    } else {
+        out.push_str(&text);
+    }
+}
+
+fn get_attribute(tag: &str, name: &str) -> Option<String> {
+    let start = tag.find(&format!("{name}=\""))? + name.len() + 2;
+    let end = tag[start..].find('"')? + start;
+    Some(decode_entities(&tag[start..end]))
+}
+
+/// Decode the named entities used on the website, as well as numeric entities.
+pub fn decode_entities(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find('&') {
+        out.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let decoded = rest.find(';').and_then(|end| {
+            let entity = &rest[1..end];
+            let c = match entity {
+                "amp" => Some('&'),
+                "lt" => Some('<'),
+                "gt" => Some('>'),
+                "quot" => Some('"'),
+                "apos" => Some('\''),
+                "nbsp" => Some(' '),
+                _ => entity
+                    .strip_prefix("#x")
+                    .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+                    .or_else(|| entity.strip_prefix('#')?.parse().ok())
+                    .and_then(char::from_u32),
+            }?;
+            Some((c, end))
+        });
+
+        match decoded {
+            Some((c, end)) => {
+                out.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    out.push_str(rest);
+    out
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{decode_entities, puzzle_to_markdown};
+
+    #[test]
+    fn decodes_entities() {
+        assert_eq!(
+            decode_entities("a &lt;b&gt; &amp; &#39;c&#x27;"),
+            "a <b> & 'c'"
+        );
+        assert_eq!(decode_entities("AT&T; &unknown;"), "AT&T; &unknown;");
+    }
+
+    #[test]
+    fn converts_puzzle_descriptions() {
+        let html = [
+            "<html><main>",
+            "<article class=\"day-desc\"><h2>--- Day 1: Test ---</h2>",
+            "<p>See <a href=\"/2024/about\">the <em>about</em> page</a>.</p>",
+            "<pre><code>1 &lt; 2\n<em>3</em> 4\n</code></pre>",
+            "<p>The answer is <code><em>11</em></code>, not <code>x</code>.</p>",
+            "<ul><li>one</li><li>two</li></ul>",
+            "</article>",
+            "<p>Your puzzle answer was <code>42</code>.</p>",
+            "<article class=\"day-desc\"><h2 id=\"part2\">--- Part Two ---</h2><p>More.</p></article>",
+            "</main></html>",
+        ]
+        .join("");
+
+        let expected = [
+            "## --- Day 1: Test ---",
+            "",
+            "See [the *about* page](/2024/about).",
+            "",
+            "```",
+            "1 < 2",
+            "3 4",
+            "```",
+            "",
+            "The answer is *`11`*, not `x`.",
+            "",
+            "- one",
+            "- two",
+            "",
+            "Your puzzle answer was `42`.",
+            "",
+            "## --- Part Two ---",
+            "",
+            "More.",
+        ]
+        .join("\n");
+
+        assert_eq!(puzzle_to_markdown(&html), expected);
+    }
+}
@@ -1,68 +1,171 @@
-use std::{env, fs};
+use std::{env, fs, io};
 
 pub mod aoc_cli;
+pub mod aoc_client;
 pub mod commands;
+pub mod registry;
 pub mod runner;
 
 pub use day::*;
+pub use year::*;
 
+mod answers;
+mod compare;
 mod day;
+mod example_tests;
+mod examples;
+mod history;
+mod html_report;
+mod markdown;
+mod readme;
 mod readme_benchmarks;
+mod readme_stars;
+mod report;
 mod run_multi;
+mod stats;
+mod status;
+mod submissions;
+mod table;
+mod templates;
 mod timings;
+mod year;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
+#[cfg(feature = "dhat-heap")]
+#[global_allocator]
+static ALLOC: dhat::Alloc = dhat::Alloc;
+
+/// Path of a file in the data folder of a year, e.g. `data/2024/inputs/01.txt`.
+#[must_use]
+pub fn get_data_path(year: Year, folder: &str, file_name: &str) -> String {
+    format!("data/{year}/{folder}/{file_name}")
+}
+
+/// Name of the solution binary of a day, e.g. `2024-01`.
+#[must_use]
+pub fn get_bin_name(year: Year, day: Day) -> String {
+    format!("{year}-{day}")
+}
+
 /// Helper function that reads a text file to a string.
 #[must_use]
-pub fn read_file(folder: &str, day: Day) -> String {
-    let cwd = env::current_dir().unwrap();
-    let filepath = cwd.join("data").join(folder).join(format!("{day}.txt"));
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+pub fn read_file(folder: &str, year: Year, day: Day) -> String {
+    try_read_file(folder, year, day).expect("could not open input file")
+}
+
+/// Helper function that reads a text file to a string, returning an error if it can not be read.
+pub fn try_read_file(folder: &str, year: Year, day: Day) -> io::Result<String> {
+    let cwd = env::current_dir()?;
+    let filepath = cwd.join(get_data_path(year, folder, &format!("{day}.txt")));
+    fs::read_to_string(filepath)
 }
 
 /// Helper function that reads a text file to string, appending a part suffix. E.g. like `01-2.txt`.
 #[must_use]
-pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
-    let cwd = env::current_dir().unwrap();
-    let filepath = cwd
-        .join("data")
-        .join(folder)
-        .join(format!("{day}-{part}.txt"));
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+pub fn read_file_part(folder: &str, year: Year, day: Day, part: u8) -> String {
+    try_read_file_part(folder, year, day, part).expect("could not open input file")
+}
+
+/// Helper function that reads a text file to string, appending a part suffix, returning an error if it can not be read.
+pub fn try_read_file_part(folder: &str, year: Year, day: Day, part: u8) -> io::Result<String> {
+    let cwd = env::current_dir()?;
+    let filepath = cwd.join(get_data_path(year, folder, &format!("{day}-{part}.txt")));
+    fs::read_to_string(filepath)
 }
 
-/// Creates the constant `DAY` and sets up the input and runner for each part.
+/// Creates the constants `YEAR` and `DAY` and sets up the input and runner for each part.
+/// The parts are also exposed as the registry entry `SOLUTION`, so the main binary can run them in-process.
 ///
 /// The optional, second parameter (1 or 2) allows you to only run a single part of the solution.
+///
+/// With `year: <year>`, the solution belongs to that year, e.g. `solution!(1, year: 2023)`.
+/// Without it, the year is taken from `AOC_YEAR` when the solution is compiled.
+///
+/// With a trailing `parse: <function>`, the input is parsed once and both parts receive a reference to the parsed input,
+/// e.g. `solution!(1, parse: parse_input)`. The parse phase is timed separately from the parts.
 #[macro_export]
 macro_rules! solution {
-    ($day:expr) => {
-        $crate::solution!(@impl $day, [part_one, 1] [part_two, 2]);
+    ($day:expr $(, year: $year:expr)?) => {
+        $crate::solution!(@impl $day, [$($year)?], [part_one, 1] [part_two, 2]);
+    };
+    ($day:expr, 1 $(, year: $year:expr)?) => {
+        $crate::solution!(@impl $day, [$($year)?], [part_one, 1]);
+    };
+    ($day:expr, 2 $(, year: $year:expr)?) => {
+        $crate::solution!(@impl $day, [$($year)?], [part_two, 2]);
+    };
+    ($day:expr $(, year: $year:expr)?, parse: $parse:expr) => {
+        $crate::solution!(@impl $day, [$($year)?], parse: $parse, [part_one, 1] [part_two, 2]);
     };
-    ($day:expr, 1) => {
-        $crate::solution!(@impl $day, [part_one, 1]);
+    ($day:expr, 1 $(, year: $year:expr)?, parse: $parse:expr) => {
+        $crate::solution!(@impl $day, [$($year)?], parse: $parse, [part_one, 1]);
     };
-    ($day:expr, 2) => {
-        $crate::solution!(@impl $day, [part_two, 2]);
+    ($day:expr, 2 $(, year: $year:expr)?, parse: $parse:expr) => {
+        $crate::solution!(@impl $day, [$($year)?], parse: $parse, [part_two, 2]);
     };
 
-    (@impl $day:expr, $( [$func:expr, $part:expr] )*) => {
+    (@impl $day:expr, [$($year:expr)?], $( [$func:expr, $part:expr] )*) => {
+        $crate::solution!(@main $day, [$($year)?]);
+
+        /// Runs all parts of the solution against an input.
+        pub fn run(input: std::sync::Arc<str>) {
+            use $crate::template::runner::*;
+            $( run_part($func, input.clone(), YEAR, DAY, $part); )*
+        }
+    };
+
+    (@impl $day:expr, [$($year:expr)?], parse: $parse:expr, $( [$func:expr, $part:expr] )*) => {
+        $crate::solution!(@main $day, [$($year)?]);
+
+        /// Parses the input once, then runs all parts of the solution against the parsed input.
+        pub fn run(input: std::sync::Arc<str>) {
+            use $crate::template::runner::*;
+            if let Some(parsed) = run_parse($parse, input, YEAR, DAY, &[$($part),*]) {
+                $( run_part($func, parsed.clone(), YEAR, DAY, $part); )*
+            }
+        }
+    };
+
+    (@year) => {
+        $crate::template::Year::__from_env(option_env!("AOC_YEAR"))
+    };
+    (@year $year:expr) => {
+        $crate::year!($year)
+    };
+
+    (@main $day:expr, [$($year:expr)?]) => {
+        /// The year of the solution.
+        const YEAR: $crate::template::Year = $crate::solution!(@year $($year)?);
+
         /// The current day.
         const DAY: $crate::template::Day = $crate::day!($day);
 
-        #[cfg(feature = "dhat-heap")]
-        #[global_allocator]
-        static ALLOC: dhat::Alloc = dhat::Alloc;
+        /// Registry entry of this solution, used when it is compiled into the main binary.
+        #[allow(dead_code)]
+        pub const SOLUTION: $crate::template::registry::Solution =
+            $crate::template::registry::Solution { year: YEAR, day: DAY, run };
 
         fn main() {
-            use $crate::template::runner::*;
-            let input = $crate::template::read_file("inputs", DAY);
-            $( run_part($func, &input, DAY, $part); )*
+            let input = match $crate::template::runner::read_input(YEAR, DAY) {
+                Ok(input) => input,
+                Err(e) => {
+                    eprintln!("{e}");
+                    std::process::exit(1);
+                }
+            };
+            let has_wrong_answers = {
+                // NOTE: the profile is written when the profiler is dropped, which `process::exit` would skip.
+                #[cfg(feature = "dhat-heap")]
+                let _profiler = $crate::template::runner::start_profiler(YEAR, DAY);
+                run(input.into());
+                $crate::template::runner::has_wrong_answers()
+            };
+            if has_wrong_answers {
+                std::process::exit(1);
+            }
         }
     };
 }
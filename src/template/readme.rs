@@ -0,0 +1,167 @@
+/// Module that updates the sections of the readme that are rendered by the template, see `cargo readme`.
+/// A section spans from a marker to the next identical marker, so a marker can carry the options of its section.
+/// Markers of the sections are `<!--- benchmarking … --->` for timings and `<!--- progress … --->` for stars.
+use std::{fs, io};
+
+use crate::template::timings::Timings;
+use crate::template::{readme_benchmarks, readme_stars};
+
+const MARKER_SUFFIX: &str = "--->";
+
+#[allow(dead_code)]
+#[derive(Debug)]
+pub enum Error {
+    Parser(String),
+    IO(io::Error),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+/// Position of a section in the readme.
+pub struct MarkerPosition {
+    /// Text of the marker, it is rendered unchanged on both ends of the section.
+    pub marker: String,
+    /// Text of the marker between its prefix and its end, e.g. `table sort=slowest`.
+    pub options: String,
+    pub pos_start: usize,
+    pub pos_end: usize,
+}
+
+/// Whether the text at `pos` is inline code or part of a fenced code block, e.g. a marker that is mentioned in the documentation.
+fn is_in_code(readme: &str, pos: usize) -> bool {
+    let fences = readme[..pos]
+        .lines()
+        .filter(|line| line.trim_start().starts_with("```"))
+        .count();
+
+    fences % 2 == 1 || readme[..pos].ends_with('`')
+}
+
+/// Locate all sections with markers that start with `prefix`, in order.
+/// A single marker is an empty section, e.g. in a readme that was not rendered to yet. Markers in code are ignored.
+pub fn locate_sections(readme: &str, prefix: &str) -> Result<Vec<MarkerPosition>, Error> {
+    let mut markers: Vec<(usize, &str)> = vec![];
+
+    let mut offset = 0;
+    while let Some(start) = readme[offset..].find(prefix).map(|i| i + offset) {
+        let end = readme[start..]
+            .find(MARKER_SUFFIX)
+            .map(|i| start + i + MARKER_SUFFIX.len())
+            .ok_or_else(|| Error::Parser(format!("Marker at {start} is not closed.")))?;
+        if !is_in_code(readme, start) {
+            markers.push((start, &readme[start..end]));
+        }
+        offset = end;
+    }
+
+    let mut sections = vec![];
+    let mut index = 0;
+
+    while let Some(&(pos_start, marker)) = markers.get(index) {
+        let occurences = markers.iter().filter(|(_, m)| *m == marker).count();
+
+        let pos_end = match occurences {
+            1 => pos_start + marker.len(),
+            2 => match markers.get(index + 1) {
+                Some(&(pos, m)) if m == marker => {
+                    index += 1;
+                    pos + marker.len()
+                }
+                _ => {
+                    return Err(Error::Parser(format!(
+                        "{marker}: sections in README must not overlap."
+                    )))
+                }
+            },
+            _ => {
+                return Err(Error::Parser(format!(
+                    "{marker}: too many occurences of marker in README."
+                )))
+            }
+        };
+
+        sections.push(MarkerPosition {
+            marker: marker.into(),
+            options: marker
+                .trim_start_matches(prefix)
+                .trim_end_matches(MARKER_SUFFIX)
+                .trim()
+                .into(),
+            pos_start,
+            pos_end,
+        });
+
+        index += 1;
+    }
+
+    Ok(sections)
+}
+
+/// Render all sections of the readme in one pass. Only sections that have a marker in the readme are rendered.
+pub fn update() -> Result<(), Error> {
+    let path = "README.md";
+    let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
+
+    let has_benchmarks = readme.contains(readme_benchmarks::MARKER_PREFIX);
+    let has_stars = readme.contains(readme_stars::MARKER_PREFIX);
+
+    if !has_benchmarks && !has_stars {
+        return Err(Error::Parser(format!(
+            "Could not find a marker in README, e.g. `{}` or `{}`.",
+            readme_benchmarks::MARKER,
+            readme_stars::MARKER
+        )));
+    }
+
+    if has_benchmarks {
+        let timings = Timings::read_all_years().map_err(Error::Parser)?;
+        readme_benchmarks::update_content(&mut readme, &timings)?;
+    }
+
+    if has_stars {
+        readme_stars::update_content(&mut readme, &readme_stars::read_all_years())?;
+    }
+
+    fs::write(path, &readme)?;
+    Ok(())
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::locate_sections;
+
+    #[test]
+    fn locates_sections() {
+        let readme = [
+            "<!--- progress stars --->",
+            "<!--- demo a --->",
+            "```text",
+            "<!--- demo b --->",
+            "```",
+            "<!--- demo a --->",
+            "Add a `<!--- demo c --->` marker.",
+            "<!--- demo d --->",
+        ]
+        .join("\n");
+
+        let sections = locate_sections(&readme, "<!--- demo ").unwrap();
+        let sections: Vec<(&str, usize, usize)> = sections
+            .iter()
+            .map(|s| (s.options.as_str(), s.pos_start, s.pos_end))
+            .collect();
+
+        assert_eq!(sections, vec![("a", 26, 91), ("d", 126, 143)]);
+    }
+
+    #[test]
+    fn errors_for_overlapping_sections() {
+        let readme = "<!--- demo a ---> <!--- demo b ---> <!--- demo a ---> <!--- demo b --->";
+        assert_eq!(locate_sections(readme, "<!--- demo ").is_err(), true);
+    }
+}
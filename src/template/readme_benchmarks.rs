@@ -1,127 +1,527 @@
 /// Module that updates the readme me with timing information.
 /// The approach taken is similar to how `aoc-readme-stars` handles this.
-use std::{fs, io};
+/// The readme can contain several sections, each delimited by a pair of identical markers that configure how it is rendered,
+/// e.g. `<!--- benchmarking table columns=part_1,part_2,share sort=slowest --->`.
+use std::str::FromStr;
 
-use crate::template::timings::Timings;
-use crate::template::Day;
+use crate::template::readme::{locate_sections, Error};
+use crate::template::report::Status;
+use crate::template::stats::{format_bytes, nanos_to_duration};
+use crate::template::timings::{PartTiming, Timing, Timings};
+use crate::template::{get_bin_name, Day, Year};
 
-static MARKER: &str = "<!--- benchmarking table --->";
+pub static MARKER: &str = "<!--- benchmarking table --->";
 
-#[allow(dead_code)]
-#[derive(Debug)]
-pub enum Error {
-    Parser(String),
-    IO(io::Error),
+pub const MARKER_PREFIX: &str = "<!--- benchmarking ";
+
+/// Characters of a unicode bar that is filled by one to seven eighths.
+const PARTIAL_BLOCKS: [char; 7] = ['▏', '▎', '▍', '▌', '▋', '▊', '▉'];
+
+/* -------------------------------------------------------------------------- */
+
+/// Kind of content that is rendered between a pair of markers.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum SectionKind {
+    /// A table with a row per day.
+    Table,
+    /// A bar chart of the share of every day in the total duration of its year.
+    Chart,
+    /// A list of the days that take longest to run.
+    Slowest,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Column {
+    Parse,
+    Part1,
+    Part2,
+    /// Combined duration of the parse phase and both parts.
+    Total,
+    /// Peak heap usage, see `cargo time --dhat`.
+    Memory,
+    /// Number of samples of every timed phase.
+    Samples,
+    /// Share of the day in the total duration of its year.
+    Share,
 }
 
-impl From<std::io::Error> for Error {
-    fn from(e: std::io::Error) -> Self {
-        Error::IO(e)
+impl Column {
+    fn header(self) -> &'static str {
+        match self {
+            Column::Parse => "Parse",
+            Column::Part1 => "Part 1",
+            Column::Part2 => "Part 2",
+            Column::Total => "Total",
+            Column::Memory => "Memory",
+            Column::Samples => "Samples",
+            Column::Share => "Share",
+        }
     }
 }
 
-pub struct TablePosition {
-    pos_start: usize,
-    pos_end: usize,
+impl FromStr for Column {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "parse" => Ok(Column::Parse),
+            "part_1" => Ok(Column::Part1),
+            "part_2" => Ok(Column::Part2),
+            "total" => Ok(Column::Total),
+            "memory" => Ok(Column::Memory),
+            "samples" => Ok(Column::Samples),
+            "share" => Ok(Column::Share),
+            _ => Err(format!("unknown column `{s}`, expected one of: parse, part_1, part_2, total, memory, samples, share.")),
+        }
+    }
 }
 
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum SortOrder {
+    Day,
+    Slowest,
+    Fastest,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum BarStyle {
+    Unicode,
+    Ascii,
+}
+
+/// Configuration of a section, parsed from the text of its marker.
+#[derive(Clone, Debug, PartialEq)]
+struct Section {
+    kind: SectionKind,
+    /// Columns of a table. If not configured, the memory column is only rendered if heap statistics were recorded for any day.
+    columns: Option<Vec<Column>>,
+    sort: SortOrder,
+    /// Maximum number of days to render per year.
+    limit: Option<usize>,
+    style: BarStyle,
+    /// Width of the bars of a chart, in characters.
+    width: usize,
+}
+
+impl FromStr for Section {
+    type Err = String;
+
+    /// Parses the contents of a marker, e.g. `chart style=ascii width=20`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut words = s.split_whitespace();
+
+        let kind = match words.next() {
+            Some("table") => SectionKind::Table,
+            Some("chart") => SectionKind::Chart,
+            Some("slowest") => SectionKind::Slowest,
+            kind => {
+                return Err(format!(
+                    "unknown section `{}`, expected one of: table, chart, slowest.",
+                    kind.unwrap_or_default()
+                ))
+            }
+        };
+
+        let mut section = Section {
+            kind,
+            columns: None,
+            sort: if kind == SectionKind::Slowest {
+                SortOrder::Slowest
+            } else {
+                SortOrder::Day
+            },
+            limit: (kind == SectionKind::Slowest).then_some(5),
+            style: BarStyle::Unicode,
+            width: 30,
+        };
+
+        for option in words {
+            let (key, value) = option.split_once('=').ok_or(format!(
+                "expected option `{option}` to be formatted as `key=value`."
+            ))?;
+
+            let number = || {
+                value
+                    .parse::<usize>()
+                    .map_err(|_| format!("expected `{key}` to be a number, got `{value}`."))
+            };
+
+            match key {
+                "columns" => {
+                    section.columns = Some(
+                        value
+                            .split(',')
+                            .map(Column::from_str)
+                            .collect::<Result<_, _>>()?,
+                    );
+                }
+                "sort" => {
+                    section.sort = match value {
+                        "day" => SortOrder::Day,
+                        "slowest" => SortOrder::Slowest,
+                        "fastest" => SortOrder::Fastest,
+                        _ => {
+                            return Err(format!(
+                            "unknown sort order `{value}`, expected one of: day, slowest, fastest."
+                        ))
+                        }
+                    };
+                }
+                "style" => {
+                    section.style = match value {
+                        "unicode" => BarStyle::Unicode,
+                        "ascii" => BarStyle::Ascii,
+                        _ => {
+                            return Err(format!(
+                                "unknown chart style `{value}`, expected one of: unicode, ascii."
+                            ))
+                        }
+                    };
+                }
+                "limit" => section.limit = Some(number()?),
+                "width" => section.width = number()?,
+                _ => {
+                    return Err(format!(
+                    "unknown option `{key}`, expected one of: columns, sort, limit, style, width."
+                ))
+                }
+            }
+        }
+
+        Ok(section)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
 #[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+pub fn get_path_for_bin(year: Year, day: Day) -> String {
+    format!("./src/bin/{}.rs", get_bin_name(year, day))
 }
 
-fn locate_table(readme: &str) -> Result<TablePosition, Error> {
-    let matches: Vec<_> = readme.match_indices(MARKER).collect();
+/* -------------------------------------------------------------------------- */
 
-    if matches.len() > 2 {
-        return Err(Error::Parser(
-            "{}: too many occurences of marker in README.".into(),
-        ));
+fn format_nanos(nanos: f64) -> String {
+    format!("{:.1?}", nanos_to_duration(nanos))
+}
+
+/// Failed parts are marked as such, so they can be told apart from parts that are not solved yet.
+fn format_part(timing: Option<&PartTiming>, status: Status) -> String {
+    timing.map_or_else(
+        || if status.is_failure() { "✖" } else { "-" }.into(),
+        PartTiming::format,
+    )
+}
+
+fn format_share(nanos: f64, total_nanos: f64) -> String {
+    if total_nanos > 0_f64 {
+        format!("{:.1}%", nanos / total_nanos * 100.0)
+    } else {
+        "-".into()
+    }
+}
+
+/// Render a bar that is filled by `ratio`, padded to `width` characters.
+#[allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss,
+    clippy::cast_precision_loss
+)]
+fn format_bar(ratio: f64, width: usize, style: BarStyle) -> String {
+    let eighths = (ratio.clamp(0_f64, 1_f64) * (width * 8) as f64).round() as usize;
+
+    let bar: String = match style {
+        BarStyle::Unicode => {
+            let mut bar = "█".repeat(eighths / 8);
+            if let Some(index) = (eighths % 8).checked_sub(1) {
+                bar.push(PARTIAL_BLOCKS[index]);
+            }
+            bar
+        }
+        BarStyle::Ascii => "#".repeat((eighths + 4) / 8),
+    };
+
+    format!("{bar:<width$}")
+}
+
+/// Days of a year in the order of the section, limited to the configured number of days.
+fn sort_timings<'a>(timings: &'a Timings, section: &Section) -> Vec<&'a Timing> {
+    let mut sorted: Vec<&Timing> = timings.data.iter().collect();
+
+    match section.sort {
+        SortOrder::Day => sorted.sort_by_key(|t| t.day),
+        SortOrder::Slowest => sorted.sort_by(|a, b| b.total_nanos().total_cmp(&a.total_nanos())),
+        SortOrder::Fastest => sorted.sort_by(|a, b| a.total_nanos().total_cmp(&b.total_nanos())),
     }
 
-    let pos_start = matches
-        .first()
-        .map(|m| m.0)
-        .ok_or_else(|| Error::Parser("Could not find table start position.".into()))?;
+    if let Some(limit) = section.limit {
+        sorted.truncate(limit);
+    }
 
-    let pos_end = matches
-        .last()
-        .map(|m| m.0 + m.1.len())
-        .ok_or_else(|| Error::Parser("Could not find table end position.".into()))?;
+    sorted
+}
 
-    Ok(TablePosition { pos_start, pos_end })
+fn format_cell(column: Column, timing: &Timing, total_nanos: f64) -> String {
+    match column {
+        Column::Parse => timing
+            .parse
+            .map_or_else(|| "-".into(), |parse| parse.format()),
+        Column::Part1 => format_part(timing.part_1.as_ref(), timing.part_1_status),
+        Column::Part2 => format_part(timing.part_2.as_ref(), timing.part_2_status),
+        Column::Total => format_nanos(timing.total_nanos()),
+        Column::Memory => timing
+            .peak_heap()
+            .map_or_else(|| "-".into(), |heap| format_bytes(heap.peak_bytes)),
+        Column::Samples => {
+            let samples: Vec<String> = [&timing.parse, &timing.part_1, &timing.part_2]
+                .into_iter()
+                .flatten()
+                .map(|part| part.samples.to_string())
+                .collect();
+            if samples.is_empty() {
+                "-".into()
+            } else {
+                samples.join(" / ")
+            }
+        }
+        Column::Share => format_share(timing.total_nanos(), total_nanos),
+    }
 }
 
-fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String {
-    let header = format!("{prefix} Benchmarks");
+fn construct_year_table(
+    prefix: &str,
+    year: Year,
+    timings: &Timings,
+    section: &Section,
+) -> Vec<String> {
+    let header = format!("{prefix} {year} Benchmarks");
+
+    let columns = section.columns.clone().unwrap_or_else(|| {
+        let mut columns = vec![Column::Parse, Column::Part1, Column::Part2];
+        if timings.data.iter().any(Timing::has_heap) {
+            columns.push(Column::Memory);
+        }
+        columns
+    });
+
+    let total_nanos = timings.total_millis() * 1_000_000_f64;
+
+    let mut lines: Vec<String> = vec![header, String::new()];
+
+    let headers: Vec<&str> = columns.iter().map(|column| column.header()).collect();
+    lines.push(format!("| Day | {} |", headers.join(" | ")));
+    lines.push(format!(
+        "| :---: | {}  |",
+        vec![":---:"; columns.len()].join(" | ")
+    ));
+
+    for timing in sort_timings(timings, section) {
+        let path = get_path_for_bin(year, timing.day);
+        let cells: Vec<String> = columns
+            .iter()
+            .map(|column| format!("`{}`", format_cell(*column, timing, total_nanos)))
+            .collect();
+
+        lines.push(format!(
+            "| [Day {}]({}) | {} |",
+            timing.day.into_inner(),
+            path,
+            cells.join(" | ")
+        ));
+    }
+
+    lines.push(String::new());
+    lines.push(format!("**Total: {:.2}ms**", timings.total_millis()));
+
+    lines
+}
+
+/// Days are rendered as bars relative to the slowest day, so the chart uses its full width.
+fn construct_year_chart(
+    prefix: &str,
+    year: Year,
+    timings: &Timings,
+    section: &Section,
+) -> Vec<String> {
+    let total_nanos = timings.total_millis() * 1_000_000_f64;
+    let max_nanos = timings
+        .data
+        .iter()
+        .map(Timing::total_nanos)
+        .fold(0_f64, f64::max);
 
     let mut lines: Vec<String> = vec![
-        MARKER.into(),
-        header,
+        format!("{prefix} {year} Relative Cost"),
         String::new(),
-        "| Day | Part 1 | Part 2 |".into(),
-        "| :---: | :---: | :---:  |".into(),
+        "```text".into(),
     ];
 
-    for timing in timings.data {
-        let path = get_path_for_bin(timing.day);
+    for timing in sort_timings(timings, section) {
+        let nanos = timing.total_nanos();
+        let ratio = if max_nanos > 0_f64 {
+            nanos / max_nanos
+        } else {
+            0_f64
+        };
+
+        lines.push(format!(
+            "Day {} {} {:>9} {:>6}",
+            timing.day,
+            format_bar(ratio, section.width, section.style),
+            format_nanos(nanos),
+            format_share(nanos, total_nanos),
+        ));
+    }
+
+    lines.push("```".into());
+
+    lines
+}
+
+/// Days without timed parts are not listed.
+fn construct_year_slowest(
+    prefix: &str,
+    year: Year,
+    timings: &Timings,
+    section: &Section,
+) -> Vec<String> {
+    let total_nanos = timings.total_millis() * 1_000_000_f64;
+
+    let mut lines: Vec<String> = vec![format!("{prefix} {year} Slowest Days"), String::new()];
+
+    let timed = Timings {
+        data: timings
+            .data
+            .iter()
+            .filter(|t| t.total_nanos() > 0_f64)
+            .cloned()
+            .collect(),
+    };
+
+    for (index, timing) in sort_timings(&timed, section).into_iter().enumerate() {
+        let nanos = timing.total_nanos();
         lines.push(format!(
-            "| [Day {}]({}) | `{}` | `{}` |",
+            "{}. [Day {}]({}): `{}` ({})",
+            index + 1,
             timing.day.into_inner(),
-            path,
-            timing.part_1.unwrap_or_else(|| "-".into()),
-            timing.part_2.unwrap_or_else(|| "-".into())
+            get_path_for_bin(year, timing.day),
+            format_nanos(nanos),
+            format_share(nanos, total_nanos),
         ));
     }
 
-    lines.push(String::new());
-    lines.push(format!("**Total: {total_millis:.2}ms**"));
-    lines.push(MARKER.into());
+    lines
+}
+
+/// Renders a section per year, in the order of `years`.
+fn construct_section(
+    prefix: &str,
+    marker: &str,
+    section: &Section,
+    years: &[(Year, Timings)],
+) -> String {
+    let mut lines: Vec<String> = vec![marker.into()];
+
+    for (index, (year, timings)) in years.iter().enumerate() {
+        if index > 0 {
+            lines.push(String::new());
+        }
+
+        lines.extend(match section.kind {
+            SectionKind::Table => construct_year_table(prefix, *year, timings, section),
+            SectionKind::Chart => construct_year_chart(prefix, *year, timings, section),
+            SectionKind::Slowest => construct_year_slowest(prefix, *year, timings, section),
+        });
+    }
+
+    lines.push(marker.into());
 
     lines.join("\n")
 }
 
-fn update_content(s: &mut String, timings: Timings, total_millis: f64) -> Result<(), Error> {
-    let positions = locate_table(s)?;
-    let table = construct_table("##", timings, total_millis);
-    s.replace_range(positions.pos_start..positions.pos_end, &table);
-    Ok(())
-}
+/// Render all sections in one pass. Sections are replaced back to front, so the positions of earlier ones stay valid.
+pub fn update_content(s: &mut String, years: &[(Year, Timings)]) -> Result<(), Error> {
+    let positions = locate_sections(s, MARKER_PREFIX)?;
+
+    if positions.is_empty() {
+        return Err(Error::Parser(format!(
+            "Could not find a benchmarking marker in README, e.g. `{MARKER}`."
+        )));
+    }
+
+    let sections = positions
+        .iter()
+        .map(|position| {
+            position
+                .options
+                .parse::<Section>()
+                .map_err(|e| Error::Parser(format!("{}: {e}", position.marker)))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    for (position, section) in positions.iter().zip(&sections).rev() {
+        let content = construct_section("##", &position.marker, section, years);
+        s.replace_range(position.pos_start..position.pos_end, &content);
+    }
 
-pub fn update(timings: Timings) -> Result<(), Error> {
-    let path = "README.md";
-    let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
-    let total_millis = timings.total_millis();
-    update_content(&mut readme, timings, total_millis)?;
-    fs::write(path, &readme)?;
     Ok(())
 }
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use super::{update_content, MARKER};
-    use crate::{day, template::timings::Timing, template::timings::Timings};
+    use super::{update_content, BarStyle, Column, Section, SectionKind, SortOrder, MARKER};
+    use crate::{
+        day, template::report::Status, template::stats::HeapStats, template::timings::PartTiming,
+        template::timings::Timing, template::timings::Timings, template::Year, year,
+    };
+
+    fn part_timing(nanos: f64) -> PartTiming {
+        PartTiming {
+            nanos,
+            samples: 1,
+            stats: None,
+        }
+    }
+
+    fn get_mock_years() -> Vec<(Year, Timings)> {
+        vec![(year!(2024), get_mock_timings())]
+    }
 
     fn get_mock_timings() -> Timings {
         Timings {
             data: vec![
                 Timing {
                     day: day!(1),
-                    part_1: Some("10ms".into()),
-                    part_2: Some("20ms".into()),
-                    total_nanos: 3e+10,
+                    parse: None,
+                    part_1: Some(part_timing(10_000_000.0)),
+                    part_2: Some(part_timing(20_000_000.0)),
+                    part_1_heap: None,
+                    part_2_heap: None,
+                    parse_heap: None,
+                    part_1_status: Status::Solved,
+                    part_2_status: Status::Solved,
                 },
                 Timing {
                     day: day!(2),
-                    part_1: Some("30ms".into()),
-                    part_2: Some("40ms".into()),
-                    total_nanos: 7e+10,
+                    parse: Some(part_timing(5_000_000.0)),
+                    part_1: Some(part_timing(30_000_000.0)),
+                    part_2: Some(part_timing(40_000_000.0)),
+                    part_1_heap: None,
+                    part_2_heap: None,
+                    parse_heap: None,
+                    part_1_status: Status::Solved,
+                    part_2_status: Status::Solved,
                 },
                 Timing {
                     day: day!(4),
-                    part_1: Some("40ms".into()),
-                    part_2: Some("50ms".into()),
-                    total_nanos: 9e+10,
+                    parse: None,
+                    part_1: Some(part_timing(40_000_000.0)),
+                    part_2: None,
+                    part_1_heap: None,
+                    part_2_heap: None,
+                    parse_heap: None,
+                    part_1_status: Status::Solved,
+                    part_2_status: Status::Failed,
                 },
             ],
         }
@@ -131,53 +531,219 @@ mod tests {
     #[should_panic]
     fn errors_if_marker_not_present() {
         let mut s = "# readme".to_string();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, &get_mock_years()).unwrap();
     }
 
     #[test]
     #[should_panic]
     fn errors_if_too_many_markers_present() {
         let mut s = format!("{} {} {}", MARKER, MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, &get_mock_years()).unwrap();
     }
 
     #[test]
     fn updates_empty_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        assert_eq!(s.contains("## Benchmarks"), true);
+        update_content(&mut s, &get_mock_years()).unwrap();
+        assert_eq!(s.contains("## 2024 Benchmarks"), true);
     }
 
     #[test]
     fn updates_existing_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, &get_mock_years()).unwrap();
+        update_content(&mut s, &get_mock_years()).unwrap();
         assert_eq!(s.matches(MARKER).collect::<Vec<&str>>().len(), 2);
-        assert_eq!(s.matches("## Benchmarks").collect::<Vec<&str>>().len(), 1);
+        assert_eq!(
+            s.matches("## 2024 Benchmarks").collect::<Vec<&str>>().len(),
+            1
+        );
     }
 
     #[test]
     fn format_benchmarks() {
         let mut s = format!("foo\nbar\n{}\n{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, &get_mock_years()).unwrap();
         let expected = [
             "foo",
             "bar",
             "<!--- benchmarking table --->",
-            "## Benchmarks",
+            "## 2024 Benchmarks",
             "",
-            "| Day | Part 1 | Part 2 |",
-            "| :---: | :---: | :---:  |",
-            "| [Day 1](./src/bin/01.rs) | `10ms` | `20ms` |",
-            "| [Day 2](./src/bin/02.rs) | `30ms` | `40ms` |",
-            "| [Day 4](./src/bin/04.rs) | `40ms` | `50ms` |",
+            "| Day | Parse | Part 1 | Part 2 |",
+            "| :---: | :---: | :---: | :---:  |",
+            "| [Day 1](./src/bin/2024-01.rs) | `-` | `10.0ms` | `20.0ms` |",
+            "| [Day 2](./src/bin/2024-02.rs) | `5.0ms` | `30.0ms` | `40.0ms` |",
+            "| [Day 4](./src/bin/2024-04.rs) | `-` | `40.0ms` | `✖` |",
             "",
-            "**Total: 190.00ms**",
+            "**Total: 145.00ms**",
             "<!--- benchmarking table --->",
             "baz",
         ]
         .join("\n");
         assert_eq!(s, expected);
     }
+
+    #[test]
+    fn format_memory_column() {
+        let mut timings = get_mock_timings();
+        timings.data[0].part_2_heap = Some(HeapStats {
+            peak_bytes: 2048,
+            total_allocations: 4,
+            total_bytes: 4096,
+        });
+
+        let mut s = format!("{}\n{}", MARKER, MARKER);
+        update_content(&mut s, &[(year!(2024), timings)]).unwrap();
+        assert_eq!(
+            s.contains("| Day | Parse | Part 1 | Part 2 | Memory |"),
+            true
+        );
+        assert_eq!(
+            s.contains("| [Day 1](./src/bin/2024-01.rs) | `-` | `10.0ms` | `20.0ms` | `2.0 KiB` |"),
+            true
+        );
+        assert_eq!(
+            s.contains("| [Day 2](./src/bin/2024-02.rs) | `5.0ms` | `30.0ms` | `40.0ms` | `-` |"),
+            true
+        );
+    }
+
+    #[test]
+    fn format_table_per_year() {
+        let mut older = get_mock_timings();
+        older.data.truncate(1);
+
+        let mut s = format!("{}\n{}", MARKER, MARKER);
+        update_content(
+            &mut s,
+            &[(year!(2024), get_mock_timings()), (year!(2023), older)],
+        )
+        .unwrap();
+
+        let expected = [
+            "**Total: 145.00ms**",
+            "",
+            "## 2023 Benchmarks",
+            "",
+            "| Day | Parse | Part 1 | Part 2 |",
+            "| :---: | :---: | :---: | :---:  |",
+            "| [Day 1](./src/bin/2023-01.rs) | `-` | `10.0ms` | `20.0ms` |",
+            "",
+            "**Total: 30.00ms**",
+            "<!--- benchmarking table --->",
+        ]
+        .join("\n");
+        assert_eq!(
+            s.starts_with(&format!("{MARKER}\n## 2024 Benchmarks")),
+            true
+        );
+        assert_eq!(s.ends_with(&expected), true);
+    }
+
+    #[test]
+    fn parses_section_options() {
+        assert_eq!(
+            "table columns=part_1,share sort=slowest limit=2".parse::<Section>(),
+            Ok(Section {
+                kind: SectionKind::Table,
+                columns: Some(vec![Column::Part1, Column::Share]),
+                sort: SortOrder::Slowest,
+                limit: Some(2),
+                style: BarStyle::Unicode,
+                width: 30,
+            })
+        );
+        assert_eq!(
+            "slowest".parse::<Section>().map(|s| (s.sort, s.limit)),
+            Ok((SortOrder::Slowest, Some(5)))
+        );
+        assert_eq!("table columns=foo".parse::<Section>().is_err(), true);
+        assert_eq!("chart width=wide".parse::<Section>().is_err(), true);
+        assert_eq!("stars".parse::<Section>().is_err(), true);
+    }
+
+    #[test]
+    fn format_custom_columns() {
+        let marker =
+            "<!--- benchmarking table columns=total,share,samples sort=slowest limit=2 --->";
+        let mut s = format!("{marker}\n{marker}");
+        update_content(&mut s, &get_mock_years()).unwrap();
+
+        let expected = [
+            marker,
+            "## 2024 Benchmarks",
+            "",
+            "| Day | Total | Share | Samples |",
+            "| :---: | :---: | :---: | :---:  |",
+            "| [Day 2](./src/bin/2024-02.rs) | `75.0ms` | `51.7%` | `1 / 1 / 1` |",
+            "| [Day 4](./src/bin/2024-04.rs) | `40.0ms` | `27.6%` | `1` |",
+            "",
+            "**Total: 145.00ms**",
+            marker,
+        ]
+        .join("\n");
+        assert_eq!(s, expected);
+    }
+
+    #[test]
+    fn format_chart() {
+        let marker = "<!--- benchmarking chart width=8 --->";
+        let mut s = marker.to_string();
+        update_content(&mut s, &get_mock_years()).unwrap();
+
+        let expected = [
+            marker,
+            "## 2024 Relative Cost",
+            "",
+            "```text",
+            "Day 01 ███▎        30.0ms  20.7%",
+            "Day 02 ████████    75.0ms  51.7%",
+            "Day 04 ████▎       40.0ms  27.6%",
+            "```",
+            marker,
+        ]
+        .join("\n");
+        assert_eq!(s, expected);
+
+        let marker = "<!--- benchmarking chart style=ascii width=10 sort=fastest limit=1 --->";
+        let mut s = marker.to_string();
+        update_content(&mut s, &get_mock_years()).unwrap();
+        assert_eq!(s.contains("Day 01 ####          30.0ms  20.7%\n```"), true);
+    }
+
+    #[test]
+    fn updates_multiple_sections() {
+        let slowest = "<!--- benchmarking slowest limit=2 --->";
+        let mut s = format!("# readme\n{MARKER}\n{MARKER}\n\n{slowest}\nfoo");
+        update_content(&mut s, &get_mock_years()).unwrap();
+        update_content(&mut s, &get_mock_years()).unwrap();
+
+        assert_eq!(
+            s.starts_with(&format!("# readme\n{MARKER}\n## 2024 Benchmarks")),
+            true
+        );
+        assert_eq!(s.matches(MARKER).count(), 2);
+        assert_eq!(s.matches(slowest).count(), 2);
+
+        let expected = [
+            slowest,
+            "## 2024 Slowest Days",
+            "",
+            "1. [Day 2](./src/bin/2024-02.rs): `75.0ms` (51.7%)",
+            "2. [Day 4](./src/bin/2024-04.rs): `40.0ms` (27.6%)",
+            slowest,
+            "foo",
+        ]
+        .join("\n");
+        assert_eq!(s.ends_with(&expected), true);
+    }
+
+    #[test]
+    #[should_panic]
+    fn errors_if_sections_overlap() {
+        let chart = "<!--- benchmarking chart --->";
+        let mut s = format!("{MARKER} {chart} {MARKER} {chart}");
+        update_content(&mut s, &get_mock_years()).unwrap();
+    }
 }
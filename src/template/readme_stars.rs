@@ -0,0 +1,243 @@
+/// Module that renders the progress of every year to the readme, as an advent calendar with the stars earned per day.
+/// A part counts as solved if its answer is known, was accepted by the website or is shown in the downloaded puzzle.
+use std::fs;
+use std::path::Path;
+
+use crate::template::answers::Answers;
+use crate::template::aoc_client::Verdict;
+use crate::template::readme::{locate_sections, Error};
+use crate::template::readme_benchmarks::get_path_for_bin;
+use crate::template::submissions::Submissions;
+use crate::template::{all_days, get_data_path, Day, Year};
+
+pub static MARKER: &str = "<!--- progress stars --->";
+
+pub const MARKER_PREFIX: &str = "<!--- progress ";
+
+/// Sentence that precedes the answer of every solved part in a downloaded puzzle description.
+const PUZZLE_ANSWER: &str = "Your puzzle answer was";
+
+const WEEKDAYS: [&str; 7] = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
+
+/// Stars earned on a single day.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DayStars {
+    pub day: Day,
+    /// The solution file exists, its day is linked in the calendar.
+    pub scaffolded: bool,
+    pub solved: [bool; 2],
+}
+
+impl DayStars {
+    pub fn collect(year: Year, day: Day, answers: &Answers, submissions: &Submissions) -> Self {
+        // every solved part shows its answer in the puzzle description, if it was downloaded after solving.
+        let puzzle_answers =
+            fs::read_to_string(get_data_path(year, "puzzles", &format!("{day}.md")))
+                .map(|markdown| markdown.matches(PUZZLE_ANSWER).count())
+                .unwrap_or_default();
+
+        let solved = [1, 2].map(|part| {
+            answers.get(day, part).is_some()
+                || submissions.data.iter().any(|record| {
+                    record.day == day && record.part == part && record.verdict == Verdict::Correct
+                })
+                || puzzle_answers >= usize::from(part)
+        });
+
+        Self {
+            day,
+            scaffolded: Path::new(&get_path_for_bin(year, day)).exists(),
+            solved,
+        }
+    }
+
+    pub fn stars(&self) -> usize {
+        self.solved.iter().filter(|solved| **solved).count()
+    }
+}
+
+/// Collect the stars of every day of a year.
+pub fn read_year(year: Year) -> Vec<DayStars> {
+    let answers = Answers::read_from_file(year).unwrap_or_else(|e| {
+        eprintln!("Failed to read the known answers: {e}");
+        Answers::default()
+    });
+    let submissions = Submissions::read_from_file(year).unwrap_or_else(|e| {
+        eprintln!("Failed to read the submission log: {e}");
+        Submissions::default()
+    });
+
+    all_days()
+        .map(|day| DayStars::collect(year, day, &answers, &submissions))
+        .collect()
+}
+
+/// Collect the stars of every year that has a data directory, newest year first.
+pub fn read_all_years() -> Vec<(Year, Vec<DayStars>)> {
+    let mut years: Vec<Year> = fs::read_dir("./data")
+        .map(|entries| {
+            entries
+                .filter_map(Result::ok)
+                .filter(|entry| entry.path().is_dir())
+                .filter_map(|entry| entry.file_name().to_str()?.parse().ok())
+                .collect()
+        })
+        .unwrap_or_default();
+
+    years.sort_unstable_by(|a, b| b.cmp(a));
+
+    years
+        .into_iter()
+        .map(|year| (year, read_year(year)))
+        .collect()
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Weekday of the first of december, `0` for monday. See Sakamoto's method.
+fn get_first_weekday(year: Year) -> usize {
+    let y = usize::from(year.into_inner());
+    // offset of december in Sakamoto's table, sunday is `0`.
+    let sunday_based = (y + y / 4 - y / 100 + y / 400 + 4 + 1) % 7;
+    (sunday_based + 6) % 7
+}
+
+fn format_day(year: Year, day: &DayStars) -> String {
+    let number = day.day.into_inner();
+    let label = if day.scaffolded {
+        format!("[{number}]({})", get_path_for_bin(year, day.day))
+    } else {
+        number.to_string()
+    };
+
+    match day.stars() {
+        0 => label,
+        stars => format!("{label}<br>{}", "⭐".repeat(stars)),
+    }
+}
+
+/// Render the days of a year as a calendar, with a column per weekday.
+fn construct_year_calendar(prefix: &str, year: Year, days: &[DayStars]) -> Vec<String> {
+    let mut lines: Vec<String> = vec![format!("{prefix} {year} Progress"), String::new()];
+
+    lines.push(format!("| {} |", WEEKDAYS.join(" | ")));
+    lines.push(format!("|{}", " :---: |".repeat(WEEKDAYS.len())));
+
+    let mut cells: Vec<String> = vec![String::new(); get_first_weekday(year)];
+    cells.extend(days.iter().map(|day| format_day(year, day)));
+
+    for week in cells.chunks(WEEKDAYS.len()) {
+        let mut week = week.to_vec();
+        week.resize(WEEKDAYS.len(), String::new());
+        lines.push(format!("| {} |", week.join(" | ")));
+    }
+
+    let stars: usize = days.iter().map(DayStars::stars).sum();
+
+    lines.push(String::new());
+    lines.push(format!("**Stars: {stars}/{}**", days.len() * 2));
+
+    lines
+}
+
+/// Renders a calendar per year, in the order of `years`.
+fn construct_section(prefix: &str, marker: &str, years: &[(Year, Vec<DayStars>)]) -> String {
+    let mut lines: Vec<String> = vec![marker.into()];
+
+    for (index, (year, days)) in years.iter().enumerate() {
+        if index > 0 {
+            lines.push(String::new());
+        }
+        lines.extend(construct_year_calendar(prefix, *year, days));
+    }
+
+    lines.push(marker.into());
+
+    lines.join("\n")
+}
+
+/// Render all progress sections in one pass.
+pub fn update_content(s: &mut String, years: &[(Year, Vec<DayStars>)]) -> Result<(), Error> {
+    let positions = locate_sections(s, MARKER_PREFIX)?;
+
+    if positions.is_empty() {
+        return Err(Error::Parser(format!(
+            "Could not find a progress marker in README, e.g. `{MARKER}`."
+        )));
+    }
+
+    if let Some(position) = positions.iter().find(|p| p.options != "stars") {
+        return Err(Error::Parser(format!(
+            "{}: unknown section `{}`, expected `stars`.",
+            position.marker, position.options
+        )));
+    }
+
+    for position in positions.iter().rev() {
+        let content = construct_section("##", &position.marker, years);
+        s.replace_range(position.pos_start..position.pos_end, &content);
+    }
+
+    Ok(())
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{get_first_weekday, update_content, DayStars, MARKER};
+    use crate::{day, template::all_days, template::Year, year};
+
+    fn get_mock_years() -> Vec<(Year, Vec<DayStars>)> {
+        let days = all_days()
+            .map(|day| DayStars {
+                day,
+                scaffolded: day == day!(1),
+                solved: [day <= day!(2), day == day!(1)],
+            })
+            .collect();
+
+        vec![(year!(2024), days)]
+    }
+
+    #[test]
+    fn computes_first_weekday() {
+        assert_eq!(get_first_weekday(year!(2024)), 6);
+        assert_eq!(get_first_weekday(year!(2023)), 4);
+        assert_eq!(get_first_weekday(year!(2015)), 1);
+    }
+
+    #[test]
+    fn format_calendar() {
+        let mut s = format!("foo\n{MARKER}\nbar");
+        update_content(&mut s, &get_mock_years()).unwrap();
+        update_content(&mut s, &get_mock_years()).unwrap();
+
+        let expected = [
+            "foo",
+            MARKER,
+            "## 2024 Progress",
+            "",
+            "| Mon | Tue | Wed | Thu | Fri | Sat | Sun |",
+            "| :---: | :---: | :---: | :---: | :---: | :---: | :---: |",
+            "|  |  |  |  |  |  | [1](./src/bin/2024-01.rs)<br>⭐⭐ |",
+            "| 2<br>⭐ | 3 | 4 | 5 | 6 | 7 | 8 |",
+            "| 9 | 10 | 11 | 12 | 13 | 14 | 15 |",
+            "| 16 | 17 | 18 | 19 | 20 | 21 | 22 |",
+            "| 23 | 24 | 25 |  |  |  |  |",
+            "",
+            "**Stars: 3/50**",
+            MARKER,
+            "bar",
+        ]
+        .join("\n");
+        assert_eq!(s, expected);
+    }
+
+    #[test]
+    #[should_panic]
+    fn errors_for_unknown_sections() {
+        let mut s = "<!--- progress calendar --->".to_string();
+        update_content(&mut s, &get_mock_years()).unwrap();
+    }
+}
@@ -0,0 +1,29 @@
+/// Registry of the solutions that are compiled into the main binary.
+/// The `solution!` macro exposes an entry for every day, `main.rs` pulls the solution bins in as modules and registers them on startup.
+use std::sync::{Arc, OnceLock};
+
+use crate::template::{Day, Year};
+
+/// A solution that can be run in-process.
+#[derive(Clone, Copy, Debug)]
+pub struct Solution {
+    pub year: Year,
+    pub day: Day,
+    /// Runs all parts of the solution against an input.
+    pub run: fn(Arc<str>),
+}
+
+static SOLUTIONS: OnceLock<&'static [Solution]> = OnceLock::new();
+
+/// Register the solutions available to the current process. Only the first call has an effect.
+pub fn register(solutions: &'static [Solution]) {
+    let _ = SOLUTIONS.set(solutions);
+}
+
+/// Look up the registered solution for a day of a year.
+pub fn find(year: Year, day: Day) -> Option<&'static Solution> {
+    SOLUTIONS
+        .get()?
+        .iter()
+        .find(|s| s.year == year && s.day == day)
+}
@@ -0,0 +1,413 @@
+/// Structured result records that solution binaries emit for the runner.
+/// When the `AOC_REPORT_FILE` environment variable is set, every part appends one JSON line to that file.
+/// Solutions that run in-process hand their records to a collector instead.
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    env,
+    fmt::Display,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::Path,
+    str::FromStr,
+};
+use tinyjson::JsonValue;
+
+use crate::template::stats::{BenchStats, HeapStats};
+use crate::template::Day;
+
+/// Environment variable that points to the file result records are appended to.
+pub const REPORT_FILE_ENV: &str = "AOC_REPORT_FILE";
+
+/// Part number of the records of the parse phase, see [`crate::template::runner::run_parse`].
+pub const PARSE_PART: u8 = 0;
+
+thread_local! {
+    static COLLECTOR: RefCell<Option<Vec<PartReport>>> = const { RefCell::new(None) };
+}
+
+/// Outcome of running a single part of a solution.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Status {
+    Solved,
+    NotSolved,
+    Panicked,
+    TimedOut,
+    /// The part returned an error.
+    Failed,
+}
+
+impl Status {
+    fn as_str(self) -> &'static str {
+        match self {
+            Status::Solved => "solved",
+            Status::NotSolved => "not_solved",
+            Status::Panicked => "panicked",
+            Status::TimedOut => "timed_out",
+            Status::Failed => "failed",
+        }
+    }
+
+    /// Whether the part ran into a problem, as opposed to being solved or not implemented yet.
+    pub fn is_failure(self) -> bool {
+        matches!(self, Status::Panicked | Status::TimedOut | Status::Failed)
+    }
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Status {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "solved" => Ok(Status::Solved),
+            "not_solved" => Ok(Status::NotSolved),
+            "panicked" => Ok(Status::Panicked),
+            "timed_out" => Ok(Status::TimedOut),
+            "failed" => Ok(Status::Failed),
+            s => Err(format!("Unknown part status `{s}`.")),
+        }
+    }
+}
+
+/// Result record for a single part of a solution.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PartReport {
+    pub day: Day,
+    /// Part of the solution, or [`PARSE_PART`] for the parse phase.
+    pub part: u8,
+    pub answer: Option<String>,
+    pub status: Status,
+    /// Explanation for parts that failed, e.g. a panic message.
+    pub message: Option<String>,
+    /// Duration of the part in nanoseconds. If the part was benched, this is the median of the samples.
+    pub duration_nanos: f64,
+    pub samples: u128,
+    pub stats: Option<BenchStats>,
+    /// Heap statistics of the first run, if the `dhat-heap` feature is enabled.
+    pub heap: Option<HeapStats>,
+    /// Known answer of the part, if one is recorded in `data/<year>/answers.json`.
+    pub expected: Option<String>,
+}
+
+impl PartReport {
+    /// Whether the part has a known answer that it did not reproduce.
+    pub fn is_wrong_answer(&self) -> bool {
+        self.expected.is_some() && self.answer != self.expected
+    }
+
+    /// Hand the record to the active collector, or append it to the report file if the runner requested one.
+    pub fn emit(&self) -> Result<(), io::Error> {
+        let collected = COLLECTOR.with_borrow_mut(|collector| match collector {
+            Some(reports) => {
+                reports.push(self.clone());
+                true
+            }
+            None => false,
+        });
+
+        if collected {
+            return Ok(());
+        }
+
+        let Ok(path) = env::var(REPORT_FILE_ENV) else {
+            return Ok(());
+        };
+
+        let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+        let json = JsonValue::from(self)
+            .stringify()
+            .map_err(io::Error::other)?;
+        writeln!(file, "{json}")
+    }
+}
+
+/// Run a function and collect the records that are emitted on the current thread while it runs.
+pub fn collect(func: impl FnOnce()) -> Vec<PartReport> {
+    let previous = COLLECTOR.replace(Some(vec![]));
+    func();
+    let reports = COLLECTOR.replace(previous);
+    reports.unwrap_or_default()
+}
+
+/// Read all records from a report file. A missing file is treated as an empty report.
+pub fn read_reports(path: &Path) -> Result<Vec<PartReport>, String> {
+    let Ok(contents) = fs::read_to_string(path) else {
+        return Ok(vec![]);
+    };
+
+    contents
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let json = JsonValue::from_str(line).or(Err("not a valid JSON record."))?;
+            PartReport::try_from(&json)
+        })
+        .collect()
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&PartReport> for JsonValue {
+    fn from(value: &PartReport) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert("part".into(), JsonValue::Number(f64::from(value.part)));
+        map.insert(
+            "answer".into(),
+            match &value.answer {
+                Some(x) => JsonValue::String(x.clone()),
+                None => JsonValue::Null,
+            },
+        );
+        map.insert("status".into(), JsonValue::String(value.status.to_string()));
+        map.insert(
+            "message".into(),
+            match &value.message {
+                Some(x) => JsonValue::String(x.clone()),
+                None => JsonValue::Null,
+            },
+        );
+        map.insert(
+            "duration_nanos".into(),
+            JsonValue::Number(value.duration_nanos),
+        );
+
+        #[allow(clippy::cast_precision_loss)]
+        map.insert("samples".into(), JsonValue::Number(value.samples as f64));
+
+        map.insert(
+            "stats".into(),
+            match &value.stats {
+                Some(x) => JsonValue::from(x),
+                None => JsonValue::Null,
+            },
+        );
+
+        map.insert(
+            "heap".into(),
+            match &value.heap {
+                Some(x) => JsonValue::from(x),
+                None => JsonValue::Null,
+            },
+        );
+
+        map.insert(
+            "expected".into(),
+            match &value.expected {
+                Some(x) => JsonValue::String(x.clone()),
+                None => JsonValue::Null,
+            },
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for PartReport {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected record to be a JSON object.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected record.day to be a Day struct.")?;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let part = json
+            .get("part")
+            .and_then(|v| v.get::<f64>())
+            .map(|x| *x as u8)
+            .ok_or("Expected record.part to be a number.")?;
+
+        let answer = json
+            .get("answer")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected record.answer to be null or string.")?;
+
+        let status = json
+            .get("status")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected record.status to be a string.")?
+            .parse()?;
+
+        let message = match json.get("message") {
+            Some(v) if !v.is_null() => Some(
+                v.get::<String>()
+                    .ok_or("Expected record.message to be null or string.")?
+                    .clone(),
+            ),
+            _ => None,
+        };
+
+        let duration_nanos = json
+            .get("duration_nanos")
+            .and_then(|v| v.get::<f64>().copied())
+            .ok_or("Expected record.duration_nanos to be a number.")?;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let samples = json
+            .get("samples")
+            .and_then(|v| v.get::<f64>())
+            .map(|x| *x as u128)
+            .ok_or("Expected record.samples to be a number.")?;
+
+        let stats = match json.get("stats") {
+            Some(v) if !v.is_null() => Some(BenchStats::try_from(v)?),
+            _ => None,
+        };
+
+        let heap = match json.get("heap") {
+            Some(v) if !v.is_null() => Some(HeapStats::try_from(v)?),
+            _ => None,
+        };
+
+        let expected = match json.get("expected") {
+            Some(v) if !v.is_null() => Some(
+                v.get::<String>()
+                    .ok_or("Expected record.expected to be null or string.")?
+                    .clone(),
+            ),
+            _ => None,
+        };
+
+        Ok(PartReport {
+            day,
+            part,
+            answer: answer.cloned(),
+            status,
+            message,
+            duration_nanos,
+            samples,
+            stats,
+            heap,
+            expected,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::str::FromStr;
+
+    use tinyjson::JsonValue;
+
+    use super::{PartReport, Status};
+    use crate::{
+        day,
+        template::stats::{BenchStats, HeapStats},
+    };
+
+    #[test]
+    fn roundtrips_records() {
+        let report = PartReport {
+            day: day!(14),
+            part: 2,
+            answer: Some("line 1\nline (2 @ 3 samples)".into()),
+            status: Status::Solved,
+            message: None,
+            duration_nanos: 1500.0,
+            samples: 10,
+            stats: Some(BenchStats {
+                mean: 1600.0,
+                median: 1500.0,
+                min: 1000.0,
+                max: 2000.0,
+                p95: 1900.0,
+                stddev: 100.0,
+                samples: 9,
+                outliers: 1,
+            }),
+            heap: Some(HeapStats {
+                peak_bytes: 1024,
+                total_allocations: 3,
+                total_bytes: 2048,
+            }),
+            expected: Some("line 1\nline (2 @ 3 samples)".into()),
+        };
+
+        let line = JsonValue::from(&report).stringify().unwrap();
+        assert_eq!(line.contains('\n'), false);
+
+        let parsed = PartReport::try_from(&JsonValue::from_str(&line).unwrap()).unwrap();
+        assert_eq!(parsed, report);
+    }
+
+    #[test]
+    fn roundtrips_failure_messages() {
+        let report = PartReport {
+            day: day!(3),
+            part: 1,
+            answer: None,
+            status: Status::Panicked,
+            message: Some("index out of bounds: the len is 3 but the index is 3".into()),
+            duration_nanos: 0.0,
+            samples: 1,
+            stats: None,
+            heap: None,
+            expected: None,
+        };
+
+        let line = JsonValue::from(&report).stringify().unwrap();
+        let parsed = PartReport::try_from(&JsonValue::from_str(&line).unwrap()).unwrap();
+        assert_eq!(parsed, report);
+    }
+
+    #[test]
+    fn handles_unsolved_parts() {
+        let json = r#"{ "day": "01", "part": 1, "answer": null, "status": "not_solved", "duration_nanos": 10, "samples": 1, "stats": null }"#;
+        let parsed = PartReport::try_from(&JsonValue::from_str(json).unwrap()).unwrap();
+        assert_eq!(parsed.answer, None);
+        assert_eq!(parsed.status, Status::NotSolved);
+        assert_eq!(parsed.stats, None);
+    }
+
+    #[test]
+    fn handles_failed_parts() {
+        let json = r#"{ "day": "01", "part": 2, "answer": null, "status": "panicked", "message": "index out of bounds", "duration_nanos": 0, "samples": 1, "stats": null }"#;
+        let parsed = PartReport::try_from(&JsonValue::from_str(json).unwrap()).unwrap();
+        assert_eq!(parsed.status, Status::Panicked);
+        assert_eq!(parsed.message, Some("index out of bounds".into()));
+    }
+
+    #[test]
+    fn handles_part_errors() {
+        let json = r#"{ "day": "05", "part": 1, "answer": null, "status": "failed", "message": "invalid digit found in string", "duration_nanos": 10, "samples": 1, "stats": null }"#;
+        let parsed = PartReport::try_from(&JsonValue::from_str(json).unwrap()).unwrap();
+        assert_eq!(parsed.status, Status::Failed);
+        assert_eq!(parsed.status.is_failure(), true);
+        assert_eq!(Status::NotSolved.is_failure(), false);
+    }
+
+    #[test]
+    fn detects_wrong_answers() {
+        let json = r#"{ "day": "01", "part": 1, "answer": "41", "status": "solved", "duration_nanos": 10, "samples": 1, "stats": null, "expected": "42" }"#;
+        let mut parsed = PartReport::try_from(&JsonValue::from_str(json).unwrap()).unwrap();
+        assert_eq!(parsed.is_wrong_answer(), true);
+        parsed.answer = Some("42".into());
+        assert_eq!(parsed.is_wrong_answer(), false);
+        parsed.expected = None;
+        parsed.answer = None;
+        assert_eq!(parsed.is_wrong_answer(), false);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_for_unknown_status() {
+        let json = r#"{ "day": "01", "part": 1, "answer": null, "status": "foo", "duration_nanos": 10, "samples": 1, "stats": null }"#;
+        PartReport::try_from(&JsonValue::from_str(json).unwrap()).unwrap();
+    }
+}
@@ -1,48 +1,316 @@
-use std::{collections::HashSet, io};
+use std::{
+    collections::{BTreeMap, HashSet},
+    io,
+    ops::ControlFlow,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc, Arc,
+    },
+    thread,
+};
 
-use crate::template::{Day, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use crate::template::{get_bin_name, Day, Year, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
 
 use super::{
-    all_days,
+    all_days, registry,
+    report::{self, PartReport},
+    runner::{self, RunOptions},
     timings::{Timing, Timings},
+    try_read_file,
 };
 
-pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -> Option<Timings> {
+/// Cargo profile that solution binaries are built with.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Profile {
+    Debug,
+    Release,
+    /// Release build with the `dhat-heap` feature, which collects heap statistics.
+    Dhat,
+}
+
+impl Profile {
+    pub fn from_release(is_release: bool) -> Self {
+        if is_release {
+            Profile::Release
+        } else {
+            Profile::Debug
+        }
+    }
+}
+
+/// Outcome of running a set of days.
+pub struct MultiRun {
+    /// Timings of the run. Durations are only meaningful for timed runs.
+    pub timings: Timings,
+    /// Parts that did not reproduce their known answer from `data/<year>/answers.json`.
+    pub wrong_answers: Vec<(Day, u8)>,
+    /// Days that could not be run, e.g. because their binary could not be spawned or its result records were malformed.
+    pub failed_days: Vec<Day>,
+}
+
+/// Run a set of days of a year. Solutions that are compiled into the current binary run in-process,
+/// all others are run via their solution binaries. Profiled runs always use the solution binaries.
+///
+/// With more than one job, days are run concurrently in the same way, see [`run_days`].
+/// Their output is buffered and printed in day order. Output that solutions print themselves (e.g. with `println!`)
+/// is only buffered for days that run via their solution binaries, in-process days print it as soon as it is written.
+/// Timed runs and runs that accept answers are always sequential.
+/// A timed run stops once a part that was run in-process timed out, see [`runner::has_abandoned_parts`].
+pub fn run_multi(
+    year: Year,
+    days_to_run: &HashSet<Day>,
+    profile: Profile,
+    jobs: usize,
+    options: RunOptions,
+) -> MultiRun {
     let mut timings: Vec<Timing> = Vec::with_capacity(days_to_run.len());
+    let mut wrong_answers: Vec<(Day, u8)> = vec![];
+    let mut failed_days: Vec<Day> = vec![];
+    let is_timed = options.is_timed;
 
-    let mut need_space = false;
+    runner::set_options(options);
 
     // NOTE: use non-duplicate, sorted day values.
-    all_days()
-        .filter(|day| days_to_run.contains(day))
-        .for_each(|day| {
-            if need_space {
-                println!();
-            }
-            need_space = true;
+    let days: Vec<Day> = all_days().filter(|day| days_to_run.contains(day)).collect();
+
+    let jobs = if is_timed && jobs > 1 {
+        eprintln!("Timed runs are not run in parallel, ignoring `--jobs {jobs}`.");
+        1
+    } else if options.accept && jobs > 1 {
+        eprintln!("Accepted answers are not recorded in parallel, ignoring `--jobs {jobs}`.");
+        1
+    } else {
+        jobs
+    };
+
+    let mut need_space = false;
 
-            println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
-            println!("------");
+    let print_header = |day: Day| {
+        if need_space {
+            println!();
+        }
+        need_space = true;
 
-            let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
+        println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+        println!("------");
+    };
 
-            if output.is_empty() {
-                println!("Not solved.");
-            } else {
-                let val = child_commands::parse_exec_time(&output, day);
-                timings.push(val);
+    let on_done = |day: Day, run: Result<DayRun, Error>| {
+        let run = match run {
+            Ok(run) => run,
+            Err(e) => {
+                eprintln!("Failed to run day {day}: {e}");
+                failed_days.push(day);
+                return ControlFlow::Continue(());
             }
-        });
+        };
+
+        print!("{}", run.stdout);
+        eprint!("{}", run.stderr);
+
+        wrong_answers.extend(
+            run.reports
+                .iter()
+                .filter(|r| r.is_wrong_answer())
+                .map(|r| (r.day, r.part)),
+        );
+
+        if run.reports.is_empty() {
+            println!("Not solved.");
+        } else {
+            timings.push(Timing::from_reports(day, &run.reports));
+        }
+
+        if is_timed && runner::has_abandoned_parts() && days.last() != Some(&day) {
+            eprintln!(
+                "Stopping the run, as a part that timed out keeps running in the background and would skew the remaining benchmarks."
+            );
+            return ControlFlow::Break(());
+        }
+
+        ControlFlow::Continue(())
+    };
+
+    run_days(
+        &days,
+        jobs,
+        |day, output| run_day(year, day, profile, options, output),
+        print_header,
+        on_done,
+    );
+
+    if !wrong_answers.is_empty() {
+        let parts = wrong_answers
+            .iter()
+            .map(|(day, part)| format!("day {day} part {part}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        println!("\n{ANSI_BOLD}✘ Known answers did not match:{ANSI_RESET} {parts}");
+    }
+
+    if !failed_days.is_empty() {
+        let days = failed_days
+            .iter()
+            .map(|day| format!("day {day}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        println!("\n{ANSI_BOLD}✘ Could not run:{ANSI_RESET} {days}");
+    }
+
+    let timings = Timings { data: timings };
 
     if is_timed {
-        let timings = Timings { data: timings };
         let total_millis = timings.total_millis();
         println!(
             "\n{ANSI_BOLD}Total (Run):{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}"
         );
-        Some(timings)
-    } else {
-        None
+    }
+
+    MultiRun {
+        timings,
+        wrong_answers,
+        failed_days,
+    }
+}
+
+/// Output and result records of a day. The output is only set if it was captured.
+#[derive(Default)]
+pub struct DayRun {
+    pub stdout: String,
+    pub stderr: String,
+    pub reports: Vec<PartReport>,
+}
+
+/// How the output of a day is handled.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Output {
+    /// Print the output while the day runs.
+    Stream,
+    /// Capture the output, so that it can be printed once the days before it are done.
+    Capture,
+}
+
+/// Run a set of days. With a single job, days run one after another on the current thread and their output is streamed.
+/// With more jobs, days run on a pool of worker threads and their output is captured.
+/// `on_start` and `on_done` are called on the current thread in the order of `days`, the run stops once `on_done` breaks.
+fn run_days<T: Send>(
+    days: &[Day],
+    jobs: usize,
+    run_day: impl Fn(Day, Output) -> T + Sync,
+    mut on_start: impl FnMut(Day),
+    mut on_done: impl FnMut(Day, T) -> ControlFlow<()>,
+) {
+    if jobs <= 1 {
+        for day in days {
+            on_start(*day);
+            if on_done(*day, run_day(*day, Output::Stream)).is_break() {
+                break;
+            }
+        }
+        return;
+    }
+
+    let next_day = AtomicUsize::new(0);
+    let (tx, rx) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.min(days.len()) {
+            let tx = tx.clone();
+            let next_day = &next_day;
+            let run_day = &run_day;
+
+            scope.spawn(move || loop {
+                let index = next_day.fetch_add(1, Ordering::Relaxed);
+                let Some(day) = days.get(index) else {
+                    break;
+                };
+                let run = run_day(*day, Output::Capture);
+                if tx.send((index, run)).is_err() {
+                    break;
+                }
+            });
+        }
+
+        drop(tx);
+
+        // hold back finished days until all days before them have been handled.
+        let mut finished = BTreeMap::new();
+        let mut next_index = 0;
+
+        'receive: for (index, run) in rx {
+            finished.insert(index, run);
+            while let Some(run) = finished.remove(&next_index) {
+                on_start(days[next_index]);
+                if on_done(days[next_index], run).is_break() {
+                    // NOTE: dropping the receiver stops the workers once their current day is done.
+                    break 'receive;
+                }
+                next_index += 1;
+            }
+        }
+    });
+}
+
+/// Run a day. Solutions that are compiled into the current binary run in-process,
+/// all others are run via their solution binaries. Profiled runs always use the solution binaries.
+fn run_day(
+    year: Year,
+    day: Day,
+    profile: Profile,
+    options: RunOptions,
+    output: Output,
+) -> Result<DayRun, Error> {
+    match registry::find(year, day).filter(|_| profile != Profile::Dhat) {
+        Some(solution) => Ok(run_in_process(solution, output)),
+        None => match output {
+            Output::Stream => {
+                child_commands::run_solution(year, day, options, profile).map(|reports| DayRun {
+                    reports,
+                    ..DayRun::default()
+                })
+            }
+            Output::Capture => child_commands::run_solution_buffered(year, day, options, profile),
+        },
+    }
+}
+
+/// Run a registered solution against its input in the current process.
+fn run_in_process(solution: &registry::Solution, output: Output) -> DayRun {
+    match try_read_file("inputs", solution.year, solution.day) {
+        Ok(input) => run_with_input(solution, input.into(), output),
+        Err(e) => {
+            let message = format!("Could not open input file for day {}: {e}", solution.day);
+            match output {
+                Output::Stream => {
+                    eprintln!("{message}");
+                    DayRun::default()
+                }
+                Output::Capture => DayRun {
+                    stderr: format!("{message}\n"),
+                    ..DayRun::default()
+                },
+            }
+        }
+    }
+}
+
+/// Run a registered solution against an input in the current process, collecting its result records.
+fn run_with_input(solution: &registry::Solution, input: Arc<str>, output: Output) -> DayRun {
+    let mut reports = vec![];
+    let run = || reports = report::collect(|| (solution.run)(input));
+
+    let stdout = match output {
+        Output::Stream => {
+            run();
+            String::new()
+        }
+        Output::Capture => runner::capture_output(run),
+    };
+
+    DayRun {
+        stdout,
+        stderr: String::new(),
+        reports,
     }
 }
 
@@ -51,6 +319,17 @@ pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -
 pub enum Error {
     BrokenPipe,
     IO(io::Error),
+    Report(String),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::BrokenPipe => write!(f, "the output of the solution could not be read"),
+            Error::IO(e) => write!(f, "{e}"),
+            Error::Report(e) => write!(f, "malformed result records: {e}"),
+        }
+    }
 }
 
 impl From<std::io::Error> for Error {
@@ -60,47 +339,126 @@ impl From<std::io::Error> for Error {
 }
 
 #[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+pub fn get_path_for_bin(year: Year, day: Day) -> String {
+    format!("./src/bin/{}.rs", get_bin_name(year, day))
 }
 
 /// All solutions live in isolated binaries.
-/// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
+/// This module encapsulates interaction with these binaries, both invoking them as well as collecting the result records they emit.
 pub mod child_commands {
-    use super::{get_path_for_bin, Error};
-    use crate::template::Day;
+    use super::{get_bin_name, get_path_for_bin, DayRun, Error, Profile};
+    use crate::template::{
+        report::{read_reports, PartReport, REPORT_FILE_ENV},
+        runner::RunOptions,
+        Day, Year,
+    };
     use std::{
+        env, fs,
         io::{BufRead, BufReader},
-        path::Path,
-        process::{Command, Stdio},
+        path::{Path, PathBuf},
+        process::{self, Command, Stdio},
         thread,
     };
 
-    /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
-        // skip command invocation for days that have not been scaffolded yet.
-        if !Path::new(&get_path_for_bin(day)).exists() {
-            return Ok(vec![]);
+    fn build_command(
+        year: Year,
+        day: Day,
+        options: RunOptions,
+        profile: Profile,
+        report_path: &Path,
+    ) -> Command {
+        let mut args = vec![
+            "run".to_string(),
+            "--quiet".to_string(),
+            "--bin".to_string(),
+            get_bin_name(year, day),
+        ];
+
+        match profile {
+            Profile::Debug => {}
+            Profile::Release => args.push("--release".into()),
+            Profile::Dhat => args.extend([
+                "--profile".into(),
+                "dhat".into(),
+                "--features".into(),
+                "dhat-heap".into(),
+            ]),
         }
 
-        let day_padded = day.to_string();
-        let mut args = vec!["run", "--quiet", "--bin", &day_padded];
+        // mirror run options like `--time` to child invocations.
+        let options = options.to_args();
 
-        if is_release {
-            args.push("--release");
+        if !options.is_empty() {
+            args.push("--".into());
+            args.extend(options);
+        }
+
+        let mut cmd = Command::new("cargo");
+        // the child appends one result record per part to this file.
+        cmd.args(&args).env(REPORT_FILE_ENV, report_path);
+        cmd
+    }
+
+    fn get_report_path(year: Year, day: Day) -> PathBuf {
+        let path = env::temp_dir().join(format!(
+            "aoc-report-{}-{}.jsonl",
+            process::id(),
+            get_bin_name(year, day)
+        ));
+        let _ = fs::remove_file(&path);
+        path
+    }
+
+    fn take_reports(report_path: &Path) -> Result<Vec<PartReport>, Error> {
+        let reports = read_reports(report_path).map_err(Error::Report);
+        let _ = fs::remove_file(report_path);
+        reports
+    }
+
+    /// Run the solution bin for a given day, capturing its output instead of forwarding it.
+    pub fn run_solution_buffered(
+        year: Year,
+        day: Day,
+        options: RunOptions,
+        profile: Profile,
+    ) -> Result<DayRun, Error> {
+        // skip command invocation for days that have not been scaffolded yet.
+        if !Path::new(&get_path_for_bin(year, day)).exists() {
+            return Ok(DayRun {
+                stdout: String::new(),
+                stderr: String::new(),
+                reports: vec![],
+            });
         }
 
-        if is_timed {
-            // mirror `--time` flag to child invocations.
-            args.push("--");
-            args.push("--time");
+        let report_path = get_report_path(year, day);
+        let output = build_command(year, day, options, profile, &report_path).output()?;
+
+        Ok(DayRun {
+            stdout: String::from_utf8_lossy(&output.stdout).into_owned(),
+            stderr: String::from_utf8_lossy(&output.stderr).into_owned(),
+            reports: take_reports(&report_path)?,
+        })
+    }
+
+    /// Run the solution bin for a given day
+    pub fn run_solution(
+        year: Year,
+        day: Day,
+        options: RunOptions,
+        profile: Profile,
+    ) -> Result<Vec<PartReport>, Error> {
+        // skip command invocation for days that have not been scaffolded yet.
+        if !Path::new(&get_path_for_bin(year, day)).exists() {
+            return Ok(vec![]);
         }
 
+        let report_path = get_report_path(year, day);
+
         // spawn child command with piped stdout/stderr.
-        // forward output to stdout/stderr while grabbing stdout lines.
+        // forward output to stdout/stderr.
 
-        let mut cmd = Command::new("cargo")
-            .args(&args)
+        let mut cmd = build_command(year, day, options, profile, &report_path)
             .stdout(Stdio::piped())
             .stderr(Stdio::piped())
             .spawn()?;
@@ -108,8 +466,6 @@ pub mod child_commands {
         let stdout = BufReader::new(cmd.stdout.take().ok_or(super::Error::BrokenPipe)?);
         let stderr = BufReader::new(cmd.stderr.take().ok_or(super::Error::BrokenPipe)?);
 
-        let mut output = vec![];
-
         let thread = thread::spawn(move || {
             stderr.lines().for_each(|line| {
                 eprintln!("{}", line.unwrap());
@@ -117,141 +473,99 @@ pub mod child_commands {
         });
 
         for line in stdout.lines() {
-            let line = line.unwrap();
-            println!("{line}");
-            output.push(line);
+            println!("{}", line.unwrap());
         }
 
         thread.join().unwrap();
         cmd.wait()?;
 
-        Ok(output)
+        take_reports(&report_path)
     }
+}
 
-    pub fn parse_exec_time(output: &[String], day: Day) -> super::Timing {
-        let mut timings = super::Timing {
-            day,
-            part_1: None,
-            part_2: None,
-            total_nanos: 0_f64,
-        };
+/* -------------------------------------------------------------------------- */
 
-        output
-            .iter()
-            .filter_map(|l| {
-                if !l.contains(" samples)") {
-                    return None;
-                }
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::ops::ControlFlow;
 
-                let Some((timing_str, nanos)) = parse_time(l) else {
-                    eprintln!("Could not parse timings from line: {l}");
-                    return None;
-                };
+    use super::{run_days, run_with_input};
+    use crate::template::{report::Status, Day};
 
-                let part = l.split(':').next()?;
-                Some((part, timing_str, nanos))
-            })
-            .for_each(|(part, timing_str, nanos)| {
-                if part.contains("Part 1") {
-                    timings.part_1 = Some(timing_str.into());
-                } else if part.contains("Part 2") {
-                    timings.part_2 = Some(timing_str.into());
-                }
+    #[allow(dead_code)]
+    mod day_01 {
+        crate::solution!(1, year: 2015);
 
-                timings.total_nanos += nanos;
-            });
+        pub fn part_one(input: &str) -> Option<usize> {
+            Some(input.len())
+        }
 
-        timings
+        pub fn part_two(_input: &str) -> Option<usize> {
+            None
+        }
     }
 
-    fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
-        s.split(postfix).next()?.parse().ok()
-    }
+    #[allow(dead_code)]
+    mod day_02 {
+        crate::solution!(2, year: 2015, parse: parse_input);
 
-    fn parse_time(line: &str) -> Option<(&str, f64)> {
-        // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-        let str_timing = line
-            .split(" samples)")
-            .next()?
-            .split('(')
-            .last()?
-            .split('@')
-            .next()?
-            .trim();
-
-        let parsed_timing = match str_timing {
-            s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
-            s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
-            s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
-            s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
-        }?;
-
-        Some((str_timing, parsed_timing))
-    }
+        pub fn parse_input(input: &str) -> (u32, u32) {
+            let mut numbers = input.split(' ').map(|x| x.parse::<u32>().unwrap());
+            (numbers.next().unwrap(), numbers.next().unwrap())
+        }
 
-    /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
-    #[cfg(feature = "test_lib")]
-    macro_rules! assert_approx_eq {
-        ($a:expr, $b:expr) => {{
-            let (a, b) = (&$a, &$b);
-            assert!(
-                (*a - *b).abs() < 1.0e-6,
-                "{} is not approximately equal to {}",
-                *a,
-                *b
-            );
-        }};
-    }
+        pub fn part_one((a, b): &(u32, u32)) -> Option<u32> {
+            Some(a + b)
+        }
 
-    #[cfg(feature = "test_lib")]
-    mod tests {
-        use super::parse_exec_time;
-
-        use crate::day;
-
-        #[test]
-        fn parses_execution_times() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: 0 (74.13ns @ 100000 samples)".into(),
-                    "Part 2: 10 (74.13ms @ 99999 samples)".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 74130074.13_f64);
-            assert_eq!(res.part_1.unwrap(), "74.13ns");
-            assert_eq!(res.part_2.unwrap(), "74.13ms");
+        pub fn part_two(_numbers: &(u32, u32)) -> Result<u32, String> {
+            Err("not implemented".into())
         }
+    }
 
-        #[test]
-        fn parses_with_patterns_in_input() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: @ @ @ ( ) ms (2s @ 5 samples)".into(),
-                    "Part 2: 10s (100ms @ 1 samples)".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 2100000000_f64);
-            assert_eq!(res.part_1.unwrap(), "2s");
-            assert_eq!(res.part_2.unwrap(), "100ms");
+    #[allow(dead_code)]
+    mod day_03 {
+        crate::solution!(3, year: 2015);
+
+        pub fn part_one(_input: &str) -> Option<u32> {
+            panic!("part one panicked")
         }
 
-        #[test]
-        fn parses_missing_parts() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: ✖        ".into(),
-                    "Part 2: ✖        ".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 0_f64);
-            assert_eq!(res.part_1.is_none(), true);
-            assert_eq!(res.part_2.is_none(), true);
+        pub fn part_two(input: &str) -> Option<String> {
+            Some(input.replace(' ', ""))
         }
     }
+
+    fn run_reports(jobs: usize) -> Vec<(Day, u8, Option<String>, Status)> {
+        let solutions = [day_01::SOLUTION, day_02::SOLUTION, day_03::SOLUTION];
+        let days: Vec<Day> = solutions.iter().map(|s| s.day).collect();
+        let mut reports = vec![];
+
+        run_days(
+            &days,
+            jobs,
+            |day, output| {
+                let solution = solutions.iter().find(|s| s.day == day).unwrap();
+                run_with_input(solution, "1 2 3".into(), output)
+            },
+            |_| {},
+            |_, run| {
+                reports.extend(run.reports);
+                ControlFlow::Continue(())
+            },
+        );
+
+        reports
+            .into_iter()
+            .map(|r| (r.day, r.part, r.answer, r.status))
+            .collect()
+    }
+
+    #[test]
+    fn runs_days_in_parallel_like_sequentially() {
+        let sequential = run_reports(1);
+        assert_eq!(sequential.len(), 7);
+        assert_eq!(run_reports(2), sequential);
+        assert_eq!(run_reports(3), sequential);
+    }
 }
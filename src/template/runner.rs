@@ -1,58 +1,741 @@
 /// Encapsulates code that interacts with solution functions.
+use std::cell::RefCell;
 use std::fmt::Display;
 use std::hint::black_box;
-use std::io::{stdout, Write};
-use std::process::Output;
+use std::io::{self, stdout, Read, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::{Arc, Mutex, OnceLock};
 use std::time::{Duration, Instant};
-use std::{cmp, env, process};
+use std::{any::Any, cmp, env, fs, process, thread};
 
+use crate::template::answers::Answers;
+use crate::template::aoc_client::{AocClient, AocClientError};
+use crate::template::report::{PartReport, Status, PARSE_PART};
+use crate::template::stats::{format_bytes, nanos_to_duration, BenchStats, HeapStats};
+use crate::template::submissions::{now_secs, SubmissionRecord, Submissions};
 use crate::template::ANSI_BOLD;
-use crate::template::{aoc_cli, Day, ANSI_ITALIC, ANSI_RESET};
+use crate::template::{
+    aoc_cli, get_data_path, try_read_file, try_read_file_part, Day, Year, ANSI_ITALIC, ANSI_RESET,
+};
+
+/// Share of the benchmark iterations that is run as warmup before samples are taken.
+const WARMUP_DIVISOR: u128 = 10;
+
+/// Stack size of the thread a part is run on. Generous, as puzzle solutions tend to recurse deeply.
+const PART_STACK_SIZE: usize = 64 * 1024 * 1024;
+
+/// Options that control how parts are run.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct RunOptions {
+    /// Bench every part instead of running it once.
+    pub is_timed: bool,
+    /// Wall-clock limit for the first run of a part.
+    pub timeout: Option<Duration>,
+    /// Record the answers of this run as the known answers.
+    pub accept: bool,
+    /// Only run this part, skipping the other one.
+    pub part: Option<u8>,
+}
+
+impl RunOptions {
+    fn from_args() -> Self {
+        let args: Vec<String> = env::args().collect();
+
+        let timeout = args.iter().position(|x| x == "--timeout").map(|i| {
+            match args.get(i + 1).map(|x| parse_timeout(x)) {
+                Some(Ok(timeout)) => timeout,
+                _ => {
+                    eprintln!("Unexpected command-line input. Format: --timeout <seconds>");
+                    process::exit(1);
+                }
+            }
+        });
+
+        let part = args.iter().position(|x| x == "--part").map(|i| {
+            match args.get(i + 1).map(|x| parse_part(x)) {
+                Some(Ok(part)) => part,
+                _ => {
+                    eprintln!("Unexpected command-line input. Format: --part <1|2>");
+                    process::exit(1);
+                }
+            }
+        });
+
+        Self {
+            is_timed: args.iter().any(|x| x == "--time"),
+            timeout,
+            accept: args.iter().any(|x| x == "--accept"),
+            part,
+        }
+    }
+
+    /// Command-line arguments that pass these options on to a solution binary.
+    pub fn to_args(&self) -> Vec<String> {
+        let mut args = vec![];
+
+        if self.is_timed {
+            args.push("--time".into());
+        }
+
+        if let Some(timeout) = self.timeout {
+            args.push("--timeout".into());
+            args.push(timeout.as_secs_f64().to_string());
+        }
+
+        if self.accept {
+            args.push("--accept".into());
+        }
+
+        if let Some(part) = self.part {
+            args.push("--part".into());
+            args.push(part.to_string());
+        }
+
+        args
+    }
+}
+
+/// Parse a timeout given in (fractional) seconds.
+pub fn parse_timeout(s: &str) -> Result<Duration, String> {
+    let secs: f64 = s
+        .parse()
+        .map_err(|_| format!("invalid timeout `{s}`, expecting a number of seconds"))?;
+    Duration::try_from_secs_f64(secs).map_err(|e| e.to_string())
+}
+
+/// Parse the number of a part.
+pub fn parse_part(s: &str) -> Result<u8, String> {
+    match s.parse() {
+        Ok(part @ (1 | 2)) => Ok(part),
+        _ => Err(format!("invalid part `{s}`, expecting 1 or 2")),
+    }
+}
+
+/// Input that a solution binary runs its parts against.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum Input {
+    /// The puzzle input, e.g. `data/2024/inputs/01.txt`.
+    #[default]
+    Puzzle,
+    /// An example: the first one is `data/2024/examples/01.txt`, further ones are e.g. `01-2.txt`.
+    Example(Option<u8>),
+    /// Any file.
+    File(String),
+    /// Standard input.
+    Stdin,
+}
+
+impl Input {
+    /// Parse `--input <path>`, `--input -` or `--example [<n>]` from the command-line arguments.
+    fn from_args() -> Result<Self, String> {
+        let args: Vec<String> = env::args().collect();
+        let input = args.iter().position(|x| x == "--input");
+        let example = args.iter().position(|x| x == "--example");
+
+        match (input, example) {
+            (Some(_), Some(_)) => Err("`--input` and `--example` can not be combined.".into()),
+            (Some(i), None) => match args.get(i + 1).map(String::as_str) {
+                Some("-") => Ok(Self::Stdin),
+                Some(path) if !path.starts_with("--") => Ok(Self::File(path.to_string())),
+                _ => Err("`--input` expects a path, or `-` to read from stdin.".into()),
+            },
+            (None, Some(i)) => match args.get(i + 1).and_then(|x| x.parse::<u8>().ok()) {
+                Some(0) => Err("examples are numbered from 1.".into()),
+                n => Ok(Self::Example(n)),
+            },
+            (None, None) => Ok(Self::Puzzle),
+        }
+    }
+
+    /// Command-line arguments that pass this input on to a solution binary.
+    pub fn to_args(&self) -> Vec<String> {
+        match self {
+            Self::Puzzle => vec![],
+            Self::Example(None) => vec!["--example".into()],
+            Self::Example(Some(n)) => vec!["--example".into(), n.to_string()],
+            Self::File(path) => vec!["--input".into(), path.clone()],
+            Self::Stdin => vec!["--input".into(), "-".into()],
+        }
+    }
+
+    pub fn is_puzzle(&self) -> bool {
+        *self == Self::Puzzle
+    }
+
+    fn read(&self, year: Year, day: Day) -> Result<String, String> {
+        let (read, path) = match self {
+            Self::Puzzle => (
+                try_read_file("inputs", year, day),
+                get_data_path(year, "inputs", &format!("{day}.txt")),
+            ),
+            Self::Example(None | Some(1)) => (
+                try_read_file("examples", year, day),
+                get_data_path(year, "examples", &format!("{day}.txt")),
+            ),
+            Self::Example(Some(n)) => (
+                try_read_file_part("examples", year, day, *n),
+                get_data_path(year, "examples", &format!("{day}-{n}.txt")),
+            ),
+            Self::File(path) => (fs::read_to_string(path), path.clone()),
+            Self::Stdin => {
+                let mut input = String::new();
+                let read = io::stdin().read_to_string(&mut input).map(|_| input);
+                (read, "stdin".into())
+            }
+        };
+
+        read.map_err(|e| match self {
+            Self::Puzzle => format!(
+                "could not read \"{path}\": {e}. Type `cargo download {day} --year {year}` to download it."
+            ),
+            _ => format!("could not read \"{path}\": {e}."),
+        })
+    }
+}
+
+static INPUT: OnceLock<Input> = OnceLock::new();
+
+/// Read the input of a solution binary, as selected by its command-line arguments.
+pub fn read_input(year: Year, day: Day) -> Result<String, String> {
+    let input = INPUT.get_or_init(|| {
+        Input::from_args().unwrap_or_else(|e| {
+            eprintln!("{e}");
+            process::exit(1);
+        })
+    });
+
+    if !input.is_puzzle() && (options().accept || env::args().any(|x| x == "--submit")) {
+        return Err("`--accept` and `--submit` only work with the puzzle input.".into());
+    }
+
+    input.read(year, day)
+}
+
+/// Whether parts run against the puzzle input. Known answers only apply to the puzzle input.
+fn is_puzzle_input() -> bool {
+    INPUT.get().is_none_or(Input::is_puzzle)
+}
+
+/// Reasons a part can fail to produce a result.
+enum Failure {
+    Panicked(String),
+    TimedOut(Duration),
+    Errored(String),
+}
+
+/// Measurements taken while running a part.
+struct Measurement {
+    /// Duration of the part. If the part was benched, this is the median of the samples.
+    duration: Duration,
+    stats: Option<BenchStats>,
+    heap: Option<HeapStats>,
+}
+
+impl Measurement {
+    fn samples(&self) -> u128 {
+        self.stats.map_or(1, |s| s.samples + s.outliers)
+    }
+
+    /// Lines with benchmark and heap statistics, printed below the result.
+    fn details(&self) -> Vec<String> {
+        self.stats
+            .as_ref()
+            .map(format_stats)
+            .into_iter()
+            .chain(self.heap.as_ref().map(format_heap))
+            .collect()
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    fn duration_nanos(&self) -> f64 {
+        self.duration.as_nanos() as f64
+    }
+}
+
+/// Output of a part function. Parts either return an `Option`, where `None` means the part is not solved yet,
+/// or a `Result`, where an error means the part failed.
+pub trait PartOutput {
+    /// The answer of the part, `None` if it is not solved, or an error message if it failed.
+    fn answer(&self) -> Result<Option<String>, String>;
+}
+
+impl<T: Display> PartOutput for Option<T> {
+    fn answer(&self) -> Result<Option<String>, String> {
+        Ok(self.as_ref().map(ToString::to_string))
+    }
+}
+
+impl<T: Display, E: Display> PartOutput for Result<T, E> {
+    fn answer(&self) -> Result<Option<String>, String> {
+        match self {
+            Ok(answer) => Ok(Some(answer.to_string())),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+}
+
+thread_local! {
+    static CAPTURED_OUTPUT: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Like `print!`, but the output is captured while [`capture_output`] runs on the current thread.
+macro_rules! out {
+    ($($arg:tt)*) => {
+        write_output(&format!($($arg)*))
+    };
+}
+
+/// Like `println!`, but the output is captured while [`capture_output`] runs on the current thread.
+macro_rules! outln {
+    ($($arg:tt)*) => {
+        write_output(&format!("{}\n", format_args!($($arg)*)))
+    };
+}
+
+fn write_output(s: &str) {
+    let is_captured = CAPTURED_OUTPUT.with_borrow_mut(|output| match output {
+        Some(output) => {
+            output.push_str(s);
+            true
+        }
+        None => false,
+    });
+
+    if !is_captured {
+        print!("{s}");
+    }
+}
+
+/// Run a function and capture what the runner prints on the current thread while it runs, e.g. the results of parts.
+/// Output of the solution itself is not captured.
+pub fn capture_output(func: impl FnOnce()) -> String {
+    let previous = CAPTURED_OUTPUT.replace(Some(String::new()));
+    func();
+    let output = CAPTURED_OUTPUT.replace(previous);
+    output.unwrap_or_default()
+}
+
+static OPTIONS: OnceLock<RunOptions> = OnceLock::new();
+
+/// Set the options for all parts run by this process.
+/// Solution binaries read them from their command-line arguments if they are not set explicitly.
+pub fn set_options(options: RunOptions) {
+    let _ = OPTIONS.set(options);
+}
+
+fn options() -> RunOptions {
+    *OPTIONS.get_or_init(RunOptions::from_args)
+}
+
+// NOTE: a process only runs solutions of a single year, so the answers of that year are loaded once.
+static ANSWERS: OnceLock<Mutex<Result<Answers, String>>> = OnceLock::new();
+
+static HAS_WRONG_ANSWERS: AtomicBool = AtomicBool::new(false);
+
+/// Whether a part run by this process did not reproduce its known answer.
+pub fn has_wrong_answers() -> bool {
+    HAS_WRONG_ANSWERS.load(Ordering::Relaxed)
+}
+
+static HAS_ABANDONED_PARTS: AtomicBool = AtomicBool::new(false);
+
+/// Whether a part run by this process timed out. Its thread keeps running in the background until the process exits,
+/// so later measurements of this process are skewed and parts are not benched anymore.
+pub fn has_abandoned_parts() -> bool {
+    HAS_ABANDONED_PARTS.load(Ordering::Relaxed)
+}
+
+/// Look up the known answer of a part. With `--accept`, the given answer is recorded as the known answer first.
+fn known_answer(year: Year, day: Day, part: u8, answer: Option<&str>) -> Option<String> {
+    if !is_puzzle_input() {
+        return None;
+    }
+
+    let mut answers = ANSWERS
+        .get_or_init(|| Mutex::new(Answers::read_from_file(year)))
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+
+    let answers = match &mut *answers {
+        Ok(answers) => answers,
+        Err(e) if options().accept => {
+            eprintln!("Not storing the answer, as the known answers could not be read: {e}");
+            return None;
+        }
+        Err(e) => {
+            eprintln!("Not checking the answer, as the known answers could not be read: {e}");
+            return None;
+        }
+    };
+
+    if let (true, Some(answer)) = (options().accept, answer) {
+        answers.set(day, part, answer.to_string());
+        if let Err(e) = answers.store_file(year) {
+            eprintln!("Failed to store known answers: {e}");
+        }
+    }
+
+    answers.get(day, part).map(ToString::to_string)
+}
+
+/// Run a part of a solution against its input, which is shared with the thread the part is run on.
+pub fn run_part<I, O, F>(func: F, input: Arc<I>, year: Year, day: Day, part: u8)
+where
+    F: Fn(&I) -> O + Copy + Send + 'static,
+    I: ?Sized + Send + Sync + 'static,
+    O: PartOutput + Send + 'static,
+{
+    if options().part.is_some_and(|p| p != part) {
+        return;
+    }
 
-pub fn run_part<I: Clone, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
     let part_str = format!("Part {part}");
 
-    let (result, duration, samples) =
-        run_timed(func, input, |result| print_result(result, &part_str, ""));
+    let run = run_timed(
+        move |input: Arc<I>| func(&input),
+        input,
+        format!("day-{day}-part-{part}"),
+        |output| {
+            if let Ok(answer) = output.answer() {
+                print_result(&answer, &part_str, "", &[], None);
+            }
+        },
+    )
+    .and_then(|(output, measurement)| match output.answer() {
+        Ok(answer) => Ok((answer, measurement)),
+        Err(message) => Err(Failure::Errored(message)),
+    });
 
-    print_result(&result, &part_str, &format_duration(&duration, samples));
+    let report = match run {
+        Ok((answer, measurement)) => {
+            let expected = known_answer(year, day, part, answer.as_deref());
 
-    if let Some(result) = result {
-        submit_result(result, day, part);
+            print_result(
+                &answer,
+                &part_str,
+                &format_duration(&measurement.duration, measurement.samples()),
+                &measurement.details(),
+                expected.as_deref(),
+            );
+
+            PartReport {
+                day,
+                part,
+                status: if answer.is_some() {
+                    Status::Solved
+                } else {
+                    Status::NotSolved
+                },
+                answer,
+                message: None,
+                duration_nanos: measurement.duration_nanos(),
+                samples: measurement.samples(),
+                stats: measurement.stats,
+                heap: measurement.heap,
+                expected,
+            }
+        }
+        Err(failure) => {
+            print_failure(&failure, &part_str);
+            failure_report(&failure, year, day, part)
+        }
+    };
+
+    record_report(&report);
+
+    if let Some(result) = report.answer {
+        submit_result(result, year, day, part);
+    }
+}
+
+/// Emit the record of a part, and remember whether it did not reproduce its known answer.
+fn record_report(report: &PartReport) {
+    if report.is_wrong_answer() {
+        HAS_WRONG_ANSWERS.store(true, Ordering::Relaxed);
+    }
+
+    if let Err(e) = report.emit() {
+        eprintln!("Failed to write result record: {e}");
+    }
+}
+
+/// Record of a part that failed, it is compared against the known answer of the part.
+fn failure_report(failure: &Failure, year: Year, day: Day, part: u8) -> PartReport {
+    let (status, message, duration) = match failure {
+        Failure::Panicked(message) => (Status::Panicked, message.clone(), Duration::ZERO),
+        Failure::TimedOut(timeout) => (
+            Status::TimedOut,
+            format!("timed out after {timeout:.1?}"),
+            *timeout,
+        ),
+        Failure::Errored(message) => (Status::Failed, message.clone(), Duration::ZERO),
+    };
+
+    PartReport {
+        day,
+        part,
+        answer: None,
+        status,
+        message: Some(message),
+        #[allow(clippy::cast_precision_loss)]
+        duration_nanos: duration.as_nanos() as f64,
+        samples: 1,
+        stats: None,
+        heap: None,
+        expected: known_answer(year, day, part, None),
+    }
+}
+
+/// Run the parse phase of a solution. Its output is shared by both parts.
+/// Returns `None` if parsing failed. The `parts` are not run then, each of them is reported as failed instead.
+pub fn run_parse<P, F>(
+    func: F,
+    input: Arc<str>,
+    year: Year,
+    day: Day,
+    parts: &[u8],
+) -> Option<Arc<P>>
+where
+    F: Fn(&str) -> P + Copy + Send + 'static,
+    P: Send + Sync + 'static,
+{
+    let part_str = "Parse";
+
+    let run = run_timed(
+        move |input: Arc<str>| func(&input),
+        input,
+        format!("day-{day}-parse"),
+        |_| {
+            out!("{part_str}:");
+        },
+    );
+
+    match run {
+        Ok((parsed, measurement)) => {
+            out!("\r");
+            outln!(
+                "{part_str}: {ANSI_ITALIC}done{ANSI_RESET}{}",
+                format_duration(&measurement.duration, measurement.samples())
+            );
+            for line in measurement.details() {
+                outln!("{line}");
+            }
+
+            record_report(&PartReport {
+                day,
+                part: PARSE_PART,
+                answer: None,
+                status: Status::Solved,
+                message: None,
+                duration_nanos: measurement.duration_nanos(),
+                samples: measurement.samples(),
+                stats: measurement.stats,
+                heap: measurement.heap,
+                expected: None,
+            });
+
+            Some(Arc::new(parsed))
+        }
+        Err(failure) => {
+            print_failure(&failure, part_str);
+            record_report(&failure_report(&failure, year, day, PARSE_PART));
+
+            let failure = Failure::Errored("parse failed".into());
+            for &part in parts
+                .iter()
+                .filter(|&&part| options().part.is_none_or(|p| p == part))
+            {
+                print_failure(&failure, &format!("Part {part}"));
+                record_report(&failure_report(&failure, year, day, part));
+            }
+
+            None
+        }
     }
 }
 
 /// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
 ///  1. in debug, the function is executed once.
 ///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
-fn run_timed<I: Clone, T>(
-    func: impl Fn(I) -> T,
+///
+/// When benched, the returned duration is the median of the samples.
+/// The first execution and the benchmark are isolated, see [`run_isolated`]. Heap statistics are taken from the first execution.
+/// Parts are not benched after a part of this process timed out, see [`has_abandoned_parts`].
+fn run_timed<I, T, F>(
+    func: F,
     input: I,
+    thread_name: String,
     hook: impl Fn(&T),
-) -> (T, Duration, u128) {
-    let timer = Instant::now();
-    let result = {
-        let input = input.clone();
+) -> Result<(T, Measurement), Failure>
+where
+    F: Fn(I) -> T + Copy + Send + 'static,
+    I: Clone + Send + 'static,
+    T: Send + 'static,
+{
+    let timeout = options().timeout;
+    let (result, base_time, heap) =
+        run_isolated(func, input.clone(), thread_name.clone(), timeout)?;
 
-        #[cfg(feature = "dhat-heap")]
-        let _profiler = dhat::Profiler::new_heap();
+    hook(&result);
 
-        func(input)
+    let measurement = if options().is_timed && !has_abandoned_parts() {
+        // NOTE: the benchmark stops itself once it exceeds the timeout, so its thread is never abandoned.
+        let (stats, _, _) = run_isolated(
+            move |input| bench(func, input, &base_time, timeout),
+            input,
+            thread_name,
+            None,
+        )?;
+        Measurement {
+            duration: nanos_to_duration(stats.median),
+            stats: Some(stats),
+            heap,
+        }
+    } else {
+        Measurement {
+            duration: base_time,
+            stats: None,
+            heap,
+        }
     };
-    let base_time = timer.elapsed();
 
-    hook(&result);
+    Ok((result, measurement))
+}
 
-    let run = if std::env::args().any(|x| x == "--time") {
-        bench(func, input, &base_time)
-    } else {
-        (base_time, 1)
+/// Run a function once on a separate thread, so that a panic does not abort the process.
+/// If the function exceeds the timeout, its thread is abandoned and keeps running in the background, see [`has_abandoned_parts`].
+/// While the heap profiler of the process is running, the heap statistics of the run are returned, see [`heap_stats`].
+fn run_isolated<I, T, F>(
+    func: F,
+    input: I,
+    thread_name: String,
+    timeout: Option<Duration>,
+) -> Result<(T, Duration, Option<HeapStats>), Failure>
+where
+    F: Fn(I) -> T + Send + 'static,
+    I: Send + 'static,
+    T: Send + 'static,
+{
+    let (tx, rx) = mpsc::channel();
+
+    let handle = thread::Builder::new()
+        .name(thread_name)
+        .stack_size(PART_STACK_SIZE)
+        .spawn(move || {
+            #[cfg(feature = "dhat-heap")]
+            let before = heap_snapshot();
+
+            let timer = Instant::now();
+            let result = func(input);
+            let elapsed = timer.elapsed();
+
+            #[cfg(feature = "dhat-heap")]
+            let heap = before
+                .zip(heap_snapshot())
+                .map(|(before, after)| heap_stats(&before, &after));
+            #[cfg(not(feature = "dhat-heap"))]
+            let heap = None;
+
+            let _ = tx.send((result, elapsed, heap));
+        })
+        .map_err(|e| Failure::Panicked(format!("could not spawn thread: {e}")))?;
+
+    let received = match timeout {
+        Some(timeout) => rx.recv_timeout(timeout),
+        None => rx.recv().map_err(|_| RecvTimeoutError::Disconnected),
     };
 
-    (result, run.0, run.1)
+    match received {
+        Ok(result) => {
+            let _ = handle.join();
+            Ok(result)
+        }
+        Err(RecvTimeoutError::Timeout) => {
+            HAS_ABANDONED_PARTS.store(true, Ordering::Relaxed);
+            Err(Failure::TimedOut(timeout.unwrap_or_default()))
+        }
+        Err(RecvTimeoutError::Disconnected) => Err(Failure::Panicked(
+            handle
+                .join()
+                .err()
+                .map_or_else(|| "unknown error".into(), panic_message),
+        )),
+    }
 }
 
-fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (Duration, u128) {
+/// Whether the heap profiler of this process is running, see [`start_profiler`].
+#[cfg(feature = "dhat-heap")]
+static IS_PROFILING: AtomicBool = AtomicBool::new(false);
+
+/// Heap profiler of a process. Its profile is written to `dhat-heap-<year>-<day>.json` when it is dropped.
+#[cfg(feature = "dhat-heap")]
+pub struct HeapProfiler {
+    _profiler: dhat::Profiler,
+}
+
+#[cfg(feature = "dhat-heap")]
+impl Drop for HeapProfiler {
+    fn drop(&mut self) {
+        IS_PROFILING.store(false, Ordering::Relaxed);
+    }
+}
+
+/// Start the heap profiler of this process. dhat tracks the allocations of the whole process,
+/// so there is a single profiler that covers all parts, instead of one per part.
+#[cfg(feature = "dhat-heap")]
+pub fn start_profiler(year: Year, day: Day) -> HeapProfiler {
+    let profiler = dhat::Profiler::builder()
+        .file_name(format!(
+            "dhat-heap-{}.json",
+            crate::template::get_bin_name(year, day)
+        ))
+        .build();
+    IS_PROFILING.store(true, Ordering::Relaxed);
+    HeapProfiler {
+        _profiler: profiler,
+    }
+}
+
+#[cfg(feature = "dhat-heap")]
+fn heap_snapshot() -> Option<dhat::HeapStats> {
+    IS_PROFILING
+        .load(Ordering::Relaxed)
+        .then(dhat::HeapStats::get)
+}
+
+/// Heap statistics of a run, from snapshots of the process taken before and after it.
+/// Allocations are counted for the whole process in between, which is only the run itself while parts run one after another.
+/// The peak is the highest heap usage of the process up to the end of the run,
+/// so it includes the input, the parsed input and the parts that ran before.
+#[cfg(feature = "dhat-heap")]
+fn heap_stats(before: &dhat::HeapStats, after: &dhat::HeapStats) -> HeapStats {
+    HeapStats {
+        peak_bytes: after.max_bytes as u64,
+        total_allocations: after.total_blocks - before.total_blocks,
+        total_bytes: after.total_bytes - before.total_bytes,
+    }
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        (*message).to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic payload".into()
+    }
+}
+
+/// Bench a function. A number of warmup iterations is run first and discarded,
+/// outliers are rejected from the remaining samples before statistics are computed.
+/// With a timeout, benching stops once it is exceeded and the samples taken so far are kept.
+fn bench<I: Clone, T>(
+    func: impl Fn(I) -> T,
+    input: I,
+    base_time: &Duration,
+    timeout: Option<Duration>,
+) -> BenchStats {
     let mut stdout = stdout();
 
     print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
@@ -61,9 +744,23 @@ fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) ->
     let bench_iterations =
         (Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10)).clamp(10, 10000);
 
+    let deadline = timeout.map(|timeout| Instant::now() + timeout);
+    let is_expired = || deadline.is_some_and(|deadline| Instant::now() >= deadline);
+
+    for _ in 0..cmp::max(bench_iterations / WARMUP_DIVISOR, 1) {
+        if is_expired() {
+            break;
+        }
+        black_box(func(black_box(input.clone())));
+    }
+
     let mut timers: Vec<Duration> = vec![];
 
-    for _ in 0..bench_iterations {
+    for i in 0..bench_iterations {
+        // the first sample is always taken, so there are statistics to report.
+        if i > 0 && is_expired() {
+            break;
+        }
         // need a clone here to make the borrow checker happy.
         let cloned = input.clone();
         let timer = Instant::now();
@@ -71,19 +768,8 @@ fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) ->
         timers.push(timer.elapsed());
     }
 
-    (
-        #[allow(clippy::cast_possible_truncation)]
-        Duration::from_nanos(average_duration(&timers) as u64),
-        bench_iterations,
-    )
-}
-
-fn average_duration(numbers: &[Duration]) -> u128 {
-    numbers
-        .iter()
-        .map(std::time::Duration::as_nanos)
-        .sum::<u128>()
-        / numbers.len() as u128
+    // NOTE: at least one sample is taken, see above.
+    BenchStats::from_samples(&timers).unwrap()
 }
 
 fn format_duration(duration: &Duration, samples: u128) -> String {
@@ -94,53 +780,116 @@ fn format_duration(duration: &Duration, samples: u128) -> String {
     }
 }
 
-fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str) {
+fn format_stats(stats: &BenchStats) -> String {
+    format!(
+        "  ↳ mean {:.1?}, min {:.1?}, max {:.1?}, p95 {:.1?}, stddev {:.1?}, {} outliers",
+        nanos_to_duration(stats.mean),
+        nanos_to_duration(stats.min),
+        nanos_to_duration(stats.max),
+        nanos_to_duration(stats.p95),
+        nanos_to_duration(stats.stddev),
+        stats.outliers
+    )
+}
+
+/// Marker that tells whether a result matches the known answer. Empty if there is no known answer.
+fn format_check(result: &str, expected: Option<&str>) -> String {
+    match expected {
+        None => String::new(),
+        Some(expected) if expected == result => " ✔".into(),
+        Some(expected) if expected.contains('\n') || result.contains('\n') => " ✘".into(),
+        Some(expected) => format!(" ✘ (expected {expected})"),
+    }
+}
+
+fn format_heap(heap: &HeapStats) -> String {
+    format!(
+        "  ↳ heap: peak {}, {} allocations, {} allocated",
+        format_bytes(heap.peak_bytes),
+        heap.total_allocations,
+        format_bytes(heap.total_bytes)
+    )
+}
+
+fn print_result<T: Display>(
+    result: &Option<T>,
+    part: &str,
+    duration_str: &str,
+    details: &[String],
+    expected: Option<&str>,
+) {
     let is_intermediate_result = duration_str.is_empty();
 
     match result {
         Some(result) => {
-            if result.to_string().contains('\n') {
-                let str = format!("{part}: ▼ {duration_str}");
+            let result = result.to_string();
+            let check_str = if is_intermediate_result {
+                String::new()
+            } else {
+                format_check(&result, expected)
+            };
+
+            if result.contains('\n') {
+                let str = format!("{part}: ▼{check_str} {duration_str}");
                 if is_intermediate_result {
-                    print!("{str}");
+                    out!("{str}");
                 } else {
-                    print!("\r");
-                    println!("{str}");
-                    println!("{result}");
+                    out!("\r");
+                    outln!("{str}");
+                    for line in details {
+                        outln!("{line}");
+                    }
+                    outln!("{result}");
+                    if let Some(expected) = expected.filter(|e| *e != result) {
+                        outln!("{ANSI_ITALIC}expected:{ANSI_RESET}\n{expected}");
+                    }
                 }
             } else {
-                let str = format!("{part}: {ANSI_BOLD}{result}{ANSI_RESET}{duration_str}");
+                let str =
+                    format!("{part}: {ANSI_BOLD}{result}{ANSI_RESET}{check_str}{duration_str}");
                 if is_intermediate_result {
-                    print!("{str}");
+                    out!("{str}");
                 } else {
-                    print!("\r");
-                    println!("{str}");
+                    out!("\r");
+                    outln!("{str}");
+                    for line in details {
+                        outln!("{line}");
+                    }
                 }
             }
         }
         None => {
             if is_intermediate_result {
-                print!("{part}: ✖");
+                out!("{part}: ✖");
             } else {
-                print!("\r");
-                println!("{part}: ✖             ");
+                out!("\r");
+                match expected {
+                    Some(expected) => outln!("{part}: ✖ (expected {expected})"),
+                    None => outln!("{part}: ✖             "),
+                }
             }
         }
     }
 }
 
+fn print_failure(failure: &Failure, part: &str) {
+    out!("\r");
+    match failure {
+        Failure::Panicked(message) => outln!("{part}: ✖ panicked: {message}"),
+        Failure::TimedOut(timeout) => outln!("{part}: ✖ timed out after {timeout:.1?}"),
+        Failure::Errored(message) => outln!("{part}: ✖ failed: {message}"),
+    }
+}
+
 /// Parse the arguments passed to `solve` and try to submit one part of the solution if:
 ///  1. we are in `--release` mode.
-///  2. aoc-cli is installed.
-fn submit_result<T: Display>(
-    result: T,
-    day: Day,
-    part: u8,
-) -> Option<Result<Output, aoc_cli::AocCommandError>> {
+///  2. a session cookie is configured, or aoc-cli is installed.
+///  3. the submission log of the year does not rule the answer out, see [`Submissions::check`].
+fn submit_result<T: Display>(result: T, year: Year, day: Day, part: u8) {
     let args: Vec<String> = env::args().collect();
 
     if !args.contains(&"--submit".into()) {
-        return None;
+        return;
     }
 
     if args.len() < 3 {
@@ -156,14 +905,56 @@ fn submit_result<T: Display>(
     };
 
     if part_submit != part {
-        return None;
+        return;
     }
 
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
+    let answer = result.to_string();
+    let mut submissions = match Submissions::read_from_file(year) {
+        Ok(submissions) => submissions,
+        Err(e) => {
+            eprintln!("Not submitting {answer}, as the submission log could not be read: {e}");
+            process::exit(1);
+        }
+    };
+
+    if let Err(refusal) = submissions.check(day, part, &answer, now_secs()) {
+        eprintln!("Not submitting {answer}: {refusal}");
         process::exit(1);
     }
 
-    println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+    let client = match AocClient::from_env() {
+        Ok(client) => client,
+        Err(AocClientError::MissingSession) if aoc_cli::check().is_ok() => {
+            println!("Submitting result via aoc-cli...");
+            // NOTE: aoc-cli prints its own response, its verdict is not recorded.
+            let _ = aoc_cli::submit(year, day, part, &answer);
+            return;
+        }
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    };
+
+    println!("Submitting result...");
+    match client.submit(year, day, part, &answer) {
+        Ok(submission) => {
+            println!("{}", submission.message);
+
+            submissions.record(SubmissionRecord::new(
+                day,
+                part,
+                &answer,
+                &submission,
+                now_secs(),
+            ));
+            if let Err(e) = submissions.store_file(year) {
+                eprintln!("Failed to store submission: {e}");
+            }
+        }
+        Err(e) => {
+            eprintln!("Failed to submit result: {e}");
+            process::exit(1);
+        }
+    }
 }
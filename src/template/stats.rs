@@ -0,0 +1,319 @@
+/// Summary statistics for a set of benchmark samples, and heap statistics of a single run.
+use std::{collections::HashMap, time::Duration};
+use tinyjson::JsonValue;
+
+/// Multiplier for the inter-quartile range used to reject outliers (Tukey's fences).
+const OUTLIER_FENCE: f64 = 1.5;
+
+/// Statistics computed from the samples of a benchmark run, in nanoseconds.
+/// Outliers are rejected before any of the values are computed.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BenchStats {
+    pub mean: f64,
+    pub median: f64,
+    pub min: f64,
+    pub max: f64,
+    pub p95: f64,
+    pub stddev: f64,
+    /// Number of samples the statistics were computed from.
+    pub samples: u128,
+    /// Number of samples that were rejected as outliers.
+    pub outliers: u128,
+}
+
+impl BenchStats {
+    /// Computes statistics for a set of samples. Returns [`None`] if there are no samples.
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        #[allow(clippy::cast_precision_loss)]
+        let mut nanos: Vec<f64> = samples.iter().map(|x| x.as_nanos() as f64).collect();
+
+        if nanos.is_empty() {
+            return None;
+        }
+
+        nanos.sort_unstable_by(f64::total_cmp);
+
+        let q1 = percentile(&nanos, 25.0);
+        let q3 = percentile(&nanos, 75.0);
+        let iqr = q3 - q1;
+        let (lower, upper) = (q1 - OUTLIER_FENCE * iqr, q3 + OUTLIER_FENCE * iqr);
+
+        let retained: Vec<f64> = nanos
+            .iter()
+            .copied()
+            .filter(|x| *x >= lower && *x <= upper)
+            .collect();
+
+        #[allow(clippy::cast_precision_loss)]
+        let count = retained.len() as f64;
+        let mean = retained.iter().sum::<f64>() / count;
+        let variance = retained.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / count;
+
+        Some(Self {
+            mean,
+            median: percentile(&retained, 50.0),
+            min: retained[0],
+            max: retained[retained.len() - 1],
+            p95: percentile(&retained, 95.0),
+            stddev: variance.sqrt(),
+            samples: retained.len() as u128,
+            outliers: (nanos.len() - retained.len()) as u128,
+        })
+    }
+}
+
+/// Linearly interpolated percentile of a sorted, non-empty slice.
+fn percentile(sorted: &[f64], p: f64) -> f64 {
+    #[allow(clippy::cast_precision_loss)]
+    let rank = p / 100.0 * (sorted.len() - 1) as f64;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let (lo, hi) = (rank.floor() as usize, rank.ceil() as usize);
+
+    sorted[lo] + (sorted[hi] - sorted[lo]) * (rank - rank.floor())
+}
+
+/// Converts nanoseconds to a [`Duration`] for display purposes.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+pub fn nanos_to_duration(nanos: f64) -> Duration {
+    Duration::from_nanos(nanos.round() as u64)
+}
+
+/// Parse a duration formatted with `{:?}`, e.g. `74.1ms`, to nanoseconds.
+pub fn parse_duration_nanos(s: &str) -> Option<f64> {
+    let split = s.find(|c: char| !c.is_ascii_digit() && c != '.')?;
+    let (value, unit) = s.split_at(split);
+    let value: f64 = value.parse().ok()?;
+
+    let factor = match unit {
+        "ns" => 1.0,
+        "µs" => 1e3,
+        "ms" => 1e6,
+        "s" => 1e9,
+        _ => return None,
+    };
+
+    Some(value * factor)
+}
+
+/// Heap statistics of a single run of a part, collected with dhat when the `dhat-heap` feature is enabled.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct HeapStats {
+    /// Bytes allocated at the peak of heap usage of the process, up to the end of the run.
+    pub peak_bytes: u64,
+    /// Number of allocations during the run.
+    pub total_allocations: u64,
+    /// Bytes allocated during the run.
+    pub total_bytes: u64,
+}
+
+impl HeapStats {
+    /// Combine the statistics of runs that happen one after another.
+    #[must_use]
+    pub fn combine(&self, other: &Self) -> Self {
+        HeapStats {
+            peak_bytes: self.peak_bytes.max(other.peak_bytes),
+            total_allocations: self.total_allocations + other.total_allocations,
+            total_bytes: self.total_bytes + other.total_bytes,
+        }
+    }
+}
+
+/// Format a number of bytes with a binary unit, e.g. `1.5 KiB`.
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    format!("{value:.1} {}", UNITS[unit])
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&BenchStats> for JsonValue {
+    fn from(value: &BenchStats) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("mean".into(), JsonValue::Number(value.mean));
+        map.insert("median".into(), JsonValue::Number(value.median));
+        map.insert("min".into(), JsonValue::Number(value.min));
+        map.insert("max".into(), JsonValue::Number(value.max));
+        map.insert("p95".into(), JsonValue::Number(value.p95));
+        map.insert("stddev".into(), JsonValue::Number(value.stddev));
+
+        #[allow(clippy::cast_precision_loss)]
+        {
+            map.insert("samples".into(), JsonValue::Number(value.samples as f64));
+            map.insert("outliers".into(), JsonValue::Number(value.outliers as f64));
+        }
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for BenchStats {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected timing stats to be a JSON object.")?;
+
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>().copied())
+                .ok_or(format!("Expected timing stats.{key} to be a number."))
+        };
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        Ok(BenchStats {
+            mean: number("mean")?,
+            median: number("median")?,
+            min: number("min")?,
+            max: number("max")?,
+            p95: number("p95")?,
+            stddev: number("stddev")?,
+            samples: number("samples")? as u128,
+            outliers: number("outliers")? as u128,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&HeapStats> for JsonValue {
+    fn from(value: &HeapStats) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        #[allow(clippy::cast_precision_loss)]
+        {
+            map.insert(
+                "peak_bytes".into(),
+                JsonValue::Number(value.peak_bytes as f64),
+            );
+            map.insert(
+                "total_allocations".into(),
+                JsonValue::Number(value.total_allocations as f64),
+            );
+            map.insert(
+                "total_bytes".into(),
+                JsonValue::Number(value.total_bytes as f64),
+            );
+        }
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for HeapStats {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected heap stats to be a JSON object.")?;
+
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>().copied())
+                .ok_or(format!("Expected heap stats.{key} to be a number."))
+        };
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        Ok(HeapStats {
+            peak_bytes: number("peak_bytes")? as u64,
+            total_allocations: number("total_allocations")? as u64,
+            total_bytes: number("total_bytes")? as u64,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{format_bytes, parse_duration_nanos, BenchStats, HeapStats};
+
+    fn nanos(values: &[u64]) -> Vec<Duration> {
+        values.iter().copied().map(Duration::from_nanos).collect()
+    }
+
+    #[test]
+    fn handles_empty_samples() {
+        assert_eq!(BenchStats::from_samples(&[]), None);
+    }
+
+    #[test]
+    fn computes_statistics() {
+        let stats = BenchStats::from_samples(&nanos(&[40, 10, 30, 20, 50])).unwrap();
+        assert_eq!(stats.mean, 30.0);
+        assert_eq!(stats.median, 30.0);
+        assert_eq!(stats.min, 10.0);
+        assert_eq!(stats.max, 50.0);
+        assert_eq!(stats.p95, 48.0);
+        assert_eq!(stats.stddev, 200_f64.sqrt());
+        assert_eq!(stats.samples, 5);
+        assert_eq!(stats.outliers, 0);
+    }
+
+    #[test]
+    fn rejects_outliers() {
+        let stats = BenchStats::from_samples(&nanos(&[10, 11, 12, 10, 11, 12, 10, 1000])).unwrap();
+        assert_eq!(stats.outliers, 1);
+        assert_eq!(stats.samples, 7);
+        assert_eq!(stats.max, 12.0);
+        assert_eq!(stats.median, 11.0);
+    }
+
+    #[test]
+    fn formats_bytes() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.0 MiB");
+    }
+
+    #[test]
+    fn combines_heap_stats() {
+        let a = HeapStats {
+            peak_bytes: 100,
+            total_allocations: 2,
+            total_bytes: 150,
+        };
+        let b = HeapStats {
+            peak_bytes: 50,
+            total_allocations: 3,
+            total_bytes: 60,
+        };
+        assert_eq!(
+            a.combine(&b),
+            HeapStats {
+                peak_bytes: 100,
+                total_allocations: 5,
+                total_bytes: 210,
+            }
+        );
+    }
+
+    #[test]
+    fn parses_durations() {
+        assert_eq!(parse_duration_nanos("39.0ns"), Some(39.0));
+        assert_eq!(parse_duration_nanos("1.5µs"), Some(1500.0));
+        assert_eq!(parse_duration_nanos("74.1ms"), Some(74_100_000.0));
+        assert_eq!(parse_duration_nanos("2s"), Some(2e9));
+        assert_eq!(parse_duration_nanos("-"), None);
+        assert_eq!(parse_duration_nanos("10 apples"), None);
+    }
+}
@@ -0,0 +1,174 @@
+//! Gathers the progress of every day of a year, see `cargo status`.
+
+use std::fs;
+use std::path::Path;
+
+use crate::template::answers::Answers;
+use crate::template::table::format_table;
+use crate::template::timings::Timings;
+use crate::template::{get_bin_name, get_data_path, Day, Year};
+
+/// Heading that starts the second part of a downloaded puzzle description.
+const PART_TWO_HEADING: &str = "--- Part Two ---";
+
+/// Progress of a single day.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DayStatus {
+    pub day: Day,
+    /// The solution file exists.
+    pub scaffolded: bool,
+    /// The input was downloaded.
+    pub has_input: bool,
+    /// Number of example files that are not empty, e.g. `01.txt` and `01-2.txt`.
+    pub examples: usize,
+    /// Parts of the downloaded puzzle description, `0` if it was not downloaded.
+    pub puzzle_parts: u8,
+    /// Whether the answers of both parts are known, see `data/<year>/answers.json`.
+    pub known_answers: [bool; 2],
+    /// Both parts have stored timings.
+    pub benchmarked: bool,
+}
+
+impl DayStatus {
+    pub fn collect(year: Year, day: Day, answers: &Answers, timings: &Timings) -> Self {
+        let is_non_empty = |path: &Path| fs::metadata(path).is_ok_and(|m| m.len() > 0);
+
+        let examples = fs::read_dir(get_data_path(year, "examples", ""))
+            .map(|entries| {
+                entries
+                    .flatten()
+                    .map(|entry| entry.path())
+                    .filter(|path| {
+                        let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+                            return false;
+                        };
+                        let Some(rest) = name.strip_prefix(&day.to_string()) else {
+                            return false;
+                        };
+                        (rest == ".txt" || rest.starts_with('-') && rest.ends_with(".txt"))
+                            && is_non_empty(path)
+                    })
+                    .count()
+            })
+            .unwrap_or_default();
+
+        let puzzle_parts =
+            match fs::read_to_string(get_data_path(year, "puzzles", &format!("{day}.md"))) {
+                Ok(markdown) if markdown.contains(PART_TWO_HEADING) => 2,
+                Ok(markdown) if !markdown.trim().is_empty() => 1,
+                _ => 0,
+            };
+
+        Self {
+            day,
+            scaffolded: Path::new(&format!("src/bin/{}.rs", get_bin_name(year, day))).exists(),
+            has_input: is_non_empty(Path::new(&get_data_path(
+                year,
+                "inputs",
+                &format!("{day}.txt"),
+            ))),
+            examples,
+            puzzle_parts,
+            known_answers: [1, 2].map(|part| answers.get(day, part).is_some()),
+            benchmarked: timings.is_day_complete(day),
+        }
+    }
+}
+
+fn check(value: bool) -> String {
+    if value { "✔" } else { "·" }.into()
+}
+
+/// Render the progress of a year as a table with a row per day, followed by totals.
+pub fn format_calendar(statuses: &[DayStatus]) -> String {
+    let rows: Vec<[String; 7]> = statuses
+        .iter()
+        .map(|status| {
+            [
+                status.day.to_string(),
+                check(status.scaffolded),
+                check(status.has_input),
+                match status.examples {
+                    0 => check(false),
+                    count => count.to_string(),
+                },
+                match status.puzzle_parts {
+                    0 => check(false),
+                    parts => format!("part {parts}"),
+                },
+                status.known_answers.map(check).join(" "),
+                check(status.benchmarked),
+            ]
+        })
+        .collect();
+
+    let table = format_table(
+        [
+            "Day", "Solution", "Input", "Examples", "Puzzle", "Answers", "Timings",
+        ],
+        &rows,
+    );
+
+    let days = statuses.len();
+    let count = |f: fn(&DayStatus) -> bool| statuses.iter().filter(|s| f(s)).count();
+    let answers: usize = statuses
+        .iter()
+        .map(|s| s.known_answers.iter().filter(|known| **known).count())
+        .sum();
+
+    let totals = [
+        format!("{}/{days} scaffolded", count(|s| s.scaffolded)),
+        format!("{}/{days} inputs", count(|s| s.has_input)),
+        format!("{}/{days} with examples", count(|s| s.examples > 0)),
+        format!("{}/{days} puzzles", count(|s| s.puzzle_parts > 0)),
+        format!("{answers}/{} answers", days * 2),
+        format!("{}/{days} benchmarked", count(|s| s.benchmarked)),
+    ];
+
+    format!("{table}\n\n{}", totals.join(", "))
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{format_calendar, DayStatus};
+    use crate::day;
+
+    #[test]
+    fn formats_calendar() {
+        let statuses = [
+            DayStatus {
+                day: day!(1),
+                scaffolded: true,
+                has_input: true,
+                examples: 2,
+                puzzle_parts: 2,
+                known_answers: [true, false],
+                benchmarked: true,
+            },
+            DayStatus {
+                day: day!(2),
+                scaffolded: false,
+                has_input: false,
+                examples: 0,
+                puzzle_parts: 0,
+                known_answers: [false, false],
+                benchmarked: false,
+            },
+        ];
+
+        assert_eq!(
+            format_calendar(&statuses),
+            [
+                "| Day | Solution | Input | Examples | Puzzle | Answers | Timings |",
+                "|-----|----------|-------|----------|--------|---------|---------|",
+                "| 01  | ✔        | ✔     | 2        | part 2 | ✔ ·     | ✔       |",
+                "| 02  | ·        | ·     | ·        | ·      | · ·     | ·       |",
+                "",
+                "1/2 scaffolded, 1/2 inputs, 1/2 with examples, 1/2 puzzles, 1/4 answers, 1/2 benchmarked",
+            ]
+            .join("\n")
+        );
+    }
+}
@@ -0,0 +1,443 @@
+/// Local log of submitted answers and their verdicts, used to refuse submissions that can not be correct.
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    fs,
+    io::{Error, ErrorKind},
+    str::FromStr,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+use tinyjson::JsonValue;
+
+use crate::template::aoc_client::{Submission, Verdict};
+use crate::template::{Day, Year};
+
+fn get_submissions_path(year: Year) -> String {
+    format!("./data/{year}/submissions.json")
+}
+
+/// Current time in seconds since the unix epoch.
+pub fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+/// A submitted answer and the verdict of the website.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SubmissionRecord {
+    pub day: Day,
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+    /// Time of the submission, in seconds since the unix epoch.
+    pub submitted_at: u64,
+    /// End of the cooldown the website asked for, in seconds since the unix epoch.
+    pub cooldown_until: Option<u64>,
+}
+
+impl SubmissionRecord {
+    pub fn new(day: Day, part: u8, answer: &str, submission: &Submission, now: u64) -> Self {
+        Self {
+            day,
+            part,
+            answer: answer.to_string(),
+            verdict: submission.verdict,
+            submitted_at: now,
+            cooldown_until: submission.cooldown().map(|wait| now + wait.as_secs()),
+        }
+    }
+
+    fn is_wrong(&self) -> bool {
+        matches!(
+            self.verdict,
+            Verdict::Incorrect | Verdict::TooHigh | Verdict::TooLow
+        )
+    }
+}
+
+/// Reason a submission was refused.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Refusal {
+    /// The answer was submitted before and is wrong.
+    KnownWrong(Verdict),
+    /// The answer is not below a previous answer that was too high.
+    AboveBound(String),
+    /// The answer is not above a previous answer that was too low.
+    BelowBound(String),
+    /// The website asked to wait before submitting again.
+    Cooldown(Duration),
+}
+
+impl Display for Refusal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Refusal::KnownWrong(Verdict::TooHigh) => {
+                write!(f, "this answer was submitted before and is too high.")
+            }
+            Refusal::KnownWrong(Verdict::TooLow) => {
+                write!(f, "this answer was submitted before and is too low.")
+            }
+            Refusal::KnownWrong(_) => write!(f, "this answer was submitted before and is wrong."),
+            Refusal::AboveBound(bound) => {
+                write!(
+                    f,
+                    "the answer must be lower than {bound}, which is too high."
+                )
+            }
+            Refusal::BelowBound(bound) => {
+                write!(
+                    f,
+                    "the answer must be higher than {bound}, which is too low."
+                )
+            }
+            Refusal::Cooldown(wait) => {
+                write!(
+                    f,
+                    "please wait {}s before submitting again.",
+                    wait.as_secs()
+                )
+            }
+        }
+    }
+}
+
+/// Represents the submission log of a year.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Submissions {
+    pub data: Vec<SubmissionRecord>,
+}
+
+impl Submissions {
+    /// Dehydrate the submissions of a year to a JSON file.
+    pub fn store_file(&self, year: Year) -> Result<(), Error> {
+        let json = JsonValue::from(self.clone());
+        let mut file = fs::File::create(get_submissions_path(year))?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate the submissions of a year from a JSON file. If not present, returns an empty log.
+    /// Files that can not be read are an error, so that they are neither ignored by checks nor overwritten.
+    pub fn read_from_file(year: Year) -> Result<Self, String> {
+        let path = get_submissions_path(year);
+
+        match fs::read_to_string(&path) {
+            Ok(contents) => Submissions::try_from(contents).map_err(|e| format!("{path}: {e}")),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Submissions::default()),
+            Err(e) => Err(format!("{path}: {e}")),
+        }
+    }
+
+    pub fn record(&mut self, record: SubmissionRecord) {
+        self.data.push(record);
+    }
+
+    /// Check whether an answer for a part can be submitted at time `now`, based on previous submissions.
+    pub fn check(&self, day: Day, part: u8, answer: &str, now: u64) -> Result<(), Refusal> {
+        let records: Vec<&SubmissionRecord> = self
+            .data
+            .iter()
+            .filter(|r| r.day == day && r.part == part)
+            .collect();
+
+        if let Some(cooldown_until) = records.iter().filter_map(|r| r.cooldown_until).max() {
+            if cooldown_until > now {
+                return Err(Refusal::Cooldown(Duration::from_secs(cooldown_until - now)));
+            }
+        }
+
+        if let Some(record) = records.iter().find(|r| r.is_wrong() && r.answer == answer) {
+            return Err(Refusal::KnownWrong(record.verdict));
+        }
+
+        // NOTE: bounds only apply to numeric answers.
+        let Ok(value) = answer.parse::<i128>() else {
+            return Ok(());
+        };
+
+        let bound = |verdict: Verdict| {
+            records
+                .iter()
+                .filter(move |r| r.verdict == verdict)
+                .filter_map(|r| r.answer.parse::<i128>().ok())
+        };
+
+        if let Some(upper) = bound(Verdict::TooHigh).min() {
+            if value >= upper {
+                return Err(Refusal::AboveBound(upper.to_string()));
+            }
+        }
+
+        if let Some(lower) = bound(Verdict::TooLow).max() {
+            if value <= lower {
+                return Err(Refusal::BelowBound(lower.to_string()));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+fn verdict_to_str(verdict: Verdict) -> &'static str {
+    match verdict {
+        Verdict::Correct => "correct",
+        Verdict::Incorrect => "incorrect",
+        Verdict::TooHigh => "too_high",
+        Verdict::TooLow => "too_low",
+        Verdict::TooRecent(_) => "too_recent",
+        Verdict::WrongLevel => "wrong_level",
+        Verdict::Unknown => "unknown",
+    }
+}
+
+fn verdict_from_str(s: &str) -> Option<Verdict> {
+    match s {
+        "correct" => Some(Verdict::Correct),
+        "incorrect" => Some(Verdict::Incorrect),
+        "too_high" => Some(Verdict::TooHigh),
+        "too_low" => Some(Verdict::TooLow),
+        // the wait time is restored from the cooldown of the record.
+        "too_recent" => Some(Verdict::TooRecent(None)),
+        "wrong_level" => Some(Verdict::WrongLevel),
+        "unknown" => Some(Verdict::Unknown),
+        _ => None,
+    }
+}
+
+impl From<Submissions> for JsonValue {
+    fn from(value: Submissions) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for Submissions {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json_data = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("data")
+            .ok_or("expected JSON document to have key `data`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.data` to be an array.")?;
+
+        Ok(Submissions {
+            data: json_data
+                .iter()
+                .map(SubmissionRecord::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&SubmissionRecord> for JsonValue {
+    fn from(value: &SubmissionRecord) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert("part".into(), JsonValue::Number(f64::from(value.part)));
+        map.insert("answer".into(), JsonValue::String(value.answer.clone()));
+        map.insert(
+            "verdict".into(),
+            JsonValue::String(verdict_to_str(value.verdict).into()),
+        );
+        #[allow(clippy::cast_precision_loss)]
+        map.insert(
+            "submitted_at".into(),
+            JsonValue::Number(value.submitted_at as f64),
+        );
+
+        if let Some(cooldown_until) = value.cooldown_until {
+            #[allow(clippy::cast_precision_loss)]
+            map.insert(
+                "cooldown_until".into(),
+                JsonValue::Number(cooldown_until as f64),
+            );
+        }
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for SubmissionRecord {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected submission to be a JSON object.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected submission.day to be a Day struct.")?;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let part = json
+            .get("part")
+            .and_then(|v| v.get::<f64>())
+            .map(|part| *part as u8)
+            .ok_or("Expected submission.part to be a number.")?;
+
+        let answer = json
+            .get("answer")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected submission.answer to be a string.")?;
+
+        let verdict = json
+            .get("verdict")
+            .and_then(|v| v.get::<String>())
+            .and_then(|verdict| verdict_from_str(verdict))
+            .ok_or("Expected submission.verdict to be a known verdict.")?;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let submitted_at = json
+            .get("submitted_at")
+            .and_then(|v| v.get::<f64>())
+            .map(|secs| *secs as u64)
+            .ok_or("Expected submission.submitted_at to be a number.")?;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let cooldown_until = match json.get("cooldown_until") {
+            Some(v) => Some(
+                v.get::<f64>()
+                    .map(|secs| *secs as u64)
+                    .ok_or("Expected submission.cooldown_until to be a number.")?,
+            ),
+            None => None,
+        };
+
+        let verdict = match verdict {
+            Verdict::TooRecent(_) => Verdict::TooRecent(
+                cooldown_until.map(|until| Duration::from_secs(until.saturating_sub(submitted_at))),
+            ),
+            verdict => verdict,
+        };
+
+        Ok(SubmissionRecord {
+            day,
+            part,
+            answer: answer.clone(),
+            verdict,
+            submitted_at,
+            cooldown_until,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{Refusal, SubmissionRecord, Submissions};
+    use crate::{day, template::aoc_client::Verdict};
+
+    fn record(
+        part: u8,
+        answer: &str,
+        verdict: Verdict,
+        cooldown_until: Option<u64>,
+    ) -> SubmissionRecord {
+        SubmissionRecord {
+            day: day!(1),
+            part,
+            answer: answer.into(),
+            verdict,
+            submitted_at: 1000,
+            cooldown_until,
+        }
+    }
+
+    #[test]
+    fn refuses_known_wrong_answers() {
+        let submissions = Submissions {
+            data: vec![record(1, "abc", Verdict::Incorrect, None)],
+        };
+
+        assert_eq!(
+            submissions.check(day!(1), 1, "abc", 2000),
+            Err(Refusal::KnownWrong(Verdict::Incorrect))
+        );
+        assert_eq!(submissions.check(day!(1), 1, "abd", 2000), Ok(()));
+        assert_eq!(submissions.check(day!(1), 2, "abc", 2000), Ok(()));
+        assert_eq!(submissions.check(day!(2), 1, "abc", 2000), Ok(()));
+    }
+
+    #[test]
+    fn refuses_answers_outside_bounds() {
+        let submissions = Submissions {
+            data: vec![
+                record(1, "100", Verdict::TooHigh, None),
+                record(1, "150", Verdict::TooHigh, None),
+                record(1, "10", Verdict::TooLow, None),
+            ],
+        };
+
+        assert_eq!(
+            submissions.check(day!(1), 1, "120", 2000),
+            Err(Refusal::AboveBound("100".into()))
+        );
+        assert_eq!(
+            submissions.check(day!(1), 1, "100", 2000),
+            Err(Refusal::KnownWrong(Verdict::TooHigh))
+        );
+        assert_eq!(
+            submissions.check(day!(1), 1, "-5", 2000),
+            Err(Refusal::BelowBound("10".into()))
+        );
+        assert_eq!(submissions.check(day!(1), 1, "50", 2000), Ok(()));
+        assert_eq!(submissions.check(day!(1), 2, "500", 2000), Ok(()));
+    }
+
+    #[test]
+    fn refuses_answers_during_cooldown() {
+        let submissions = Submissions {
+            data: vec![record(1, "100", Verdict::Incorrect, Some(1060))],
+        };
+
+        assert_eq!(
+            submissions.check(day!(1), 1, "50", 1030),
+            Err(Refusal::Cooldown(Duration::from_secs(30)))
+        );
+        assert_eq!(submissions.check(day!(1), 1, "50", 1060), Ok(()));
+    }
+
+    #[test]
+    fn roundtrips_submissions() {
+        let submissions = Submissions {
+            data: vec![
+                record(1, "100", Verdict::TooHigh, Some(1060)),
+                record(
+                    2,
+                    "7",
+                    Verdict::TooRecent(Some(Duration::from_secs(42))),
+                    Some(1042),
+                ),
+                record(2, "8", Verdict::Correct, None),
+            ],
+        };
+
+        let json = tinyjson::JsonValue::from(submissions.clone())
+            .stringify()
+            .unwrap();
+        assert_eq!(Submissions::try_from(json).unwrap(), submissions);
+    }
+}
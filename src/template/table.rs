@@ -0,0 +1,35 @@
+//! Renders column-aligned tables for the terminal, e.g. for `cargo test-day` and `cargo status`.
+
+/// Render rows as a markdown-style table. Columns are padded to their widest cell.
+pub fn format_table<const N: usize>(headers: [&str; N], rows: &[[String; N]]) -> String {
+    let headers = headers.map(String::from);
+
+    let widths: Vec<usize> = (0..N)
+        .map(|i| {
+            rows.iter()
+                .chain([&headers])
+                .map(|row| row[i].chars().count())
+                .max()
+                .unwrap_or_default()
+        })
+        .collect();
+
+    let format_row = |row: &[String; N]| {
+        let cells: Vec<String> = row
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{cell:<width$}"))
+            .collect();
+        format!("| {} |", cells.join(" | "))
+    };
+
+    let separator = widths
+        .iter()
+        .map(|width| "-".repeat(width + 2))
+        .collect::<Vec<_>>()
+        .join("|");
+
+    let mut lines = vec![format_row(&headers), format!("|{separator}|")];
+    lines.extend(rows.iter().map(format_row));
+    lines.join("\n")
+}
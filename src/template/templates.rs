@@ -0,0 +1,324 @@
+//! Solution templates for `cargo scaffold`, see `--template`.
+//! User-defined templates live in the `templates` folder, the default template is embedded.
+
+use std::fmt::Display;
+use std::fs;
+
+use crate::template::{Day, Year};
+
+/// Folder that user-defined templates are loaded from, e.g. `templates/grid.txt`.
+pub const TEMPLATES_DIR: &str = "templates";
+
+/// Template used when none is selected. It can be overridden with `templates/default.txt`.
+pub const DEFAULT_TEMPLATE: &str = "default";
+
+const DEFAULT_CONTENTS: &str =
+    include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/template.txt"));
+
+fn get_template_path(name: &str) -> String {
+    format!("{TEMPLATES_DIR}/{name}.txt")
+}
+
+/// Names of all available templates, sorted.
+pub fn available() -> Vec<String> {
+    let mut names = vec![DEFAULT_TEMPLATE.to_string()];
+
+    if let Ok(entries) = fs::read_dir(TEMPLATES_DIR) {
+        names.extend(entries.flatten().filter_map(|entry| {
+            let path = entry.path();
+            if path.extension()? != "txt" {
+                return None;
+            }
+            Some(path.file_stem()?.to_str()?.to_string())
+        }));
+    }
+
+    names.sort();
+    names.dedup();
+    names
+}
+
+/// Load a template by name.
+pub fn load(name: &str) -> Result<String, String> {
+    match fs::read_to_string(get_template_path(name)) {
+        Ok(contents) => Ok(contents),
+        Err(_) if name == DEFAULT_TEMPLATE => Ok(DEFAULT_CONTENTS.to_string()),
+        Err(_) => Err(format!(
+            "template `{name}` not found, available templates: {}.",
+            available().join(", ")
+        )),
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Return type of the scaffolded parts, inferred from the answers to the examples.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum AnswerType {
+    U32,
+    U64,
+    I64,
+    String,
+}
+
+impl AnswerType {
+    /// The smallest type that fits all known answers, `u32` if there are none.
+    pub fn infer<'a>(answers: impl IntoIterator<Item = Option<&'a str>>) -> Self {
+        answers
+            .into_iter()
+            .flatten()
+            .map(|answer| {
+                if answer.parse::<u32>().is_ok() {
+                    Self::U32
+                } else if answer.parse::<u64>().is_ok() {
+                    Self::U64
+                } else if answer.parse::<i64>().is_ok() {
+                    Self::I64
+                } else {
+                    Self::String
+                }
+            })
+            .fold(Self::U32, Self::widen)
+    }
+
+    fn widen(self, other: Self) -> Self {
+        match (self, other) {
+            (Self::String, _) | (_, Self::String) => Self::String,
+            (Self::I64, _) | (_, Self::I64) => Self::I64,
+            (Self::U64, _) | (_, Self::U64) => Self::U64,
+            _ => Self::U32,
+        }
+    }
+
+    /// Expected value of a scaffolded test. Answers that do not fit the type are left out.
+    pub fn format_expected(self, answer: Option<&str>) -> String {
+        let fits = |answer: &&str| match self {
+            Self::U32 => answer.parse::<u32>().is_ok(),
+            Self::U64 => answer.parse::<u64>().is_ok(),
+            Self::I64 => answer.parse::<i64>().is_ok(),
+            Self::String => true,
+        };
+
+        match (answer.filter(fits), self) {
+            (Some(answer), Self::String) => format!("Some({answer:?}.to_string())"),
+            (Some(answer), _) => format!("Some({answer})"),
+            (None, _) => "None".into(),
+        }
+    }
+}
+
+impl Display for AnswerType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::U32 => "u32",
+            Self::U64 => "u64",
+            Self::I64 => "i64",
+            Self::String => "String",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Values of the placeholders of a template.
+pub struct Placeholders {
+    pub year: Year,
+    pub day: Day,
+    /// Title of the puzzle, if its description was downloaded.
+    pub title: Option<String>,
+    /// Answers to the examples of both parts, if they were extracted.
+    pub answers: [Option<String>; 2],
+}
+
+impl Placeholders {
+    /// Replace the placeholders of a template:
+    /// `%YEAR%`, `%DAY_NUMBER%` (e.g. `1`), `%DAY%` (e.g. `01`), `%TITLE%`, `%ANSWER_TYPE%`,
+    /// `%PART_ONE_ANSWER%` and `%PART_TWO_ANSWER%`.
+    pub fn render(&self, template: &str) -> String {
+        let [part_one, part_two] = self.answers.each_ref().map(Option::as_deref);
+        let answer_type = AnswerType::infer([part_one, part_two]);
+
+        let title = match &self.title {
+            Some(title) => title.clone(),
+            None => format!("Day {}", self.day.into_inner()),
+        };
+
+        template
+            .replace("%YEAR%", &self.year.to_string())
+            .replace("%DAY_NUMBER%", &self.day.into_inner().to_string())
+            .replace("%DAY%", &self.day.to_string())
+            .replace("%TITLE%", &title)
+            .replace("%ANSWER_TYPE%", &answer_type.to_string())
+            .replace("%PART_ONE_ANSWER%", &answer_type.format_expected(part_one))
+            .replace("%PART_TWO_ANSWER%", &answer_type.format_expected(part_two))
+    }
+}
+
+/// Title of a downloaded puzzle description, e.g. `Day 1: Historian Hysteria`.
+pub fn puzzle_title(markdown: &str) -> Option<String> {
+    let heading = markdown.lines().find(|line| line.starts_with("## "))?;
+    let title = heading
+        .trim_start_matches("## ")
+        .trim_matches(|c: char| c == '-' || c.is_whitespace());
+    (!title.is_empty()).then(|| title.to_string())
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Suggest a template from the shape of an input:
+/// `blocks` for inputs with sections separated by empty lines,
+/// `numbers` for lines of integers and `grid` for rectangular character grids.
+pub fn suggest(input: &str) -> Option<&'static str> {
+    let input = input.replace("\r\n", "\n");
+    let input = input.trim_end();
+    let lines: Vec<&str> = input.lines().collect();
+
+    if lines.is_empty() {
+        return None;
+    }
+
+    if input.contains("\n\n") {
+        return Some("blocks");
+    }
+
+    let is_numbers = lines.iter().all(|line| {
+        let mut tokens = line
+            .split(|c: char| !c.is_ascii_digit() && c != '-')
+            .filter(|token| !token.is_empty() && *token != "-")
+            .peekable();
+
+        !line.contains(char::is_alphabetic)
+            && tokens.peek().is_some()
+            && tokens.all(|token| token.parse::<i64>().is_ok())
+    });
+
+    if is_numbers {
+        return Some("numbers");
+    }
+
+    let width = lines[0].chars().count();
+    let is_grid = lines.len() > 1
+        && width > 1
+        && lines
+            .iter()
+            .all(|line| line.chars().count() == width && !line.contains(char::is_whitespace));
+
+    if is_grid {
+        return Some("grid");
+    }
+
+    None
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// The bundled templates, rendered by `build.rs` and compiled as modules. Their own tests run against the examples.
+#[cfg(feature = "test_lib")]
+#[allow(warnings, clippy::all, clippy::pedantic)]
+mod rendered {
+    include!(concat!(env!("OUT_DIR"), "/templates/mod.rs"));
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::fs;
+
+    use super::{puzzle_title, rendered, suggest, AnswerType, Placeholders};
+    use crate::{day, year};
+
+    #[test]
+    fn compiles_bundled_templates() {
+        let placeholders = Placeholders {
+            year: year!(2024),
+            day: day!(1),
+            title: None,
+            answers: [None, None],
+        };
+
+        let names: Vec<&str> = rendered::RENDERED.iter().map(|(name, ..)| *name).collect();
+        assert_eq!(names, vec!["blocks", "default", "grid", "numbers"]);
+
+        // the compiled modules were rendered by `build.rs`, they must match what `cargo scaffold` renders.
+        for (name, path, contents) in rendered::RENDERED {
+            let template = fs::read_to_string(path).unwrap();
+            assert_eq!(
+                placeholders.render(&template),
+                *contents,
+                "template `{name}`"
+            );
+        }
+    }
+
+    #[test]
+    fn infers_answer_types() {
+        assert_eq!(AnswerType::infer([None, None]), AnswerType::U32);
+        assert_eq!(AnswerType::infer([Some("11"), None]), AnswerType::U32);
+        assert_eq!(
+            AnswerType::infer([Some("11"), Some("5000000000")]),
+            AnswerType::U64
+        );
+        assert_eq!(AnswerType::infer([Some("-3"), None]), AnswerType::I64);
+        assert_eq!(
+            AnswerType::infer([Some("11"), Some("a,b")]),
+            AnswerType::String
+        );
+
+        assert_eq!(AnswerType::U32.format_expected(Some("11")), "Some(11)");
+        assert_eq!(AnswerType::U32.format_expected(Some("-1")), "None");
+        assert_eq!(
+            AnswerType::String.format_expected(Some("a,b")),
+            "Some(\"a,b\".to_string())"
+        );
+        assert_eq!(AnswerType::I64.format_expected(None), "None");
+    }
+
+    #[test]
+    fn renders_placeholders() {
+        let placeholders = Placeholders {
+            year: year!(2024),
+            day: day!(1),
+            title: Some("Day 1: Historian Hysteria".into()),
+            answers: [Some("11".into()), None],
+        };
+
+        assert_eq!(
+            placeholders.render(
+                "//! %TITLE%\nsolution!(%DAY_NUMBER%, year: %YEAR%); // %DAY%\n\
+                -> Option<%ANSWER_TYPE%>\n%PART_ONE_ANSWER% %PART_TWO_ANSWER%"
+            ),
+            "//! Day 1: Historian Hysteria\nsolution!(1, year: 2024); // 01\n\
+            -> Option<u32>\nSome(11) None"
+        );
+
+        let untitled = Placeholders {
+            title: None,
+            ..placeholders
+        };
+        assert_eq!(untitled.render("%TITLE%"), "Day 1");
+    }
+
+    #[test]
+    fn finds_puzzle_titles() {
+        assert_eq!(
+            puzzle_title("## --- Day 1: Historian Hysteria ---\n\nText"),
+            Some("Day 1: Historian Hysteria".into())
+        );
+        assert_eq!(puzzle_title("no heading"), None);
+    }
+
+    #[test]
+    fn suggests_templates() {
+        assert_eq!(suggest("3   4\n4   3\n"), Some("numbers"));
+        assert_eq!(suggest("190: 10 19\n-3,4\n"), Some("numbers"));
+        assert_eq!(suggest("MMMS\nXAXA\n"), Some("grid"));
+        assert_eq!(suggest("0123\n1234\n"), Some("numbers"));
+        assert_eq!(
+            suggest(&format!("{}\n{}\n", "8".repeat(40), "9".repeat(40))),
+            Some("grid")
+        );
+        assert_eq!(suggest("47|53\n97|13\n\n75,47,61\n"), Some("blocks"));
+        assert_eq!(suggest("xmul(2,4)%&mul[3,7]\n"), None);
+        assert_eq!(suggest(""), None);
+    }
+}
@@ -1,17 +1,158 @@
-use std::{collections::HashMap, fs, io::Error, str::FromStr};
+use std::{
+    collections::HashMap,
+    fs,
+    io::{Error, ErrorKind},
+    path::Path,
+    str::FromStr,
+};
 use tinyjson::JsonValue;
 
-use crate::template::Day;
+use crate::template::report::{PartReport, Status, PARSE_PART};
+use crate::template::stats::{nanos_to_duration, parse_duration_nanos, BenchStats, HeapStats};
+use crate::template::{Day, Year};
 
-static TIMINGS_FILE_PATH: &str = "./data/timings.json";
+/// Version of the schema of `timings.json`. Files without a version were stored before it was introduced.
+/// - `1`: durations are stored as formatted strings, e.g. `"74.1ms"`, next to a combined `total_nanos`.
+/// - `2`: durations are stored per part in nanoseconds, together with the number of samples.
+pub const TIMINGS_VERSION: u8 = 2;
+
+fn get_timings_path(year: Year) -> String {
+    format!("./data/{year}/timings.json")
+}
+
+/// Benchmark result of a single part, or of the parse phase.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PartTiming {
+    /// Duration in nanoseconds. If the part was benched, this is the median of the samples.
+    pub nanos: f64,
+    /// Number of times the part was run.
+    pub samples: u128,
+    pub stats: Option<BenchStats>,
+}
+
+impl PartTiming {
+    /// Format the duration for display, e.g. `74.1ms`.
+    pub fn format(&self) -> String {
+        format!("{:.1?}", nanos_to_duration(self.nanos))
+    }
+}
 
 /// Represents benchmark times for a single day.
 #[derive(Clone, Debug)]
 pub struct Timing {
     pub day: Day,
-    pub part_1: Option<String>,
-    pub part_2: Option<String>,
-    pub total_nanos: f64,
+    /// Duration of the parse phase, for solutions that parse their input once for both parts.
+    pub parse: Option<PartTiming>,
+    pub part_1: Option<PartTiming>,
+    pub part_2: Option<PartTiming>,
+    /// Heap statistics, only present for timings that were profiled with the `dhat-heap` feature.
+    pub part_1_heap: Option<HeapStats>,
+    pub part_2_heap: Option<HeapStats>,
+    pub parse_heap: Option<HeapStats>,
+    pub part_1_status: Status,
+    pub part_2_status: Status,
+}
+
+impl Timing {
+    /// A timing without any durations, for a day that has not been run.
+    fn empty(day: Day) -> Self {
+        Timing {
+            day,
+            parse: None,
+            part_1: None,
+            part_2: None,
+            part_1_heap: None,
+            part_2_heap: None,
+            parse_heap: None,
+            part_1_status: Status::NotSolved,
+            part_2_status: Status::NotSolved,
+        }
+    }
+
+    /// Timing of a part, or of the parse phase for [`PARSE_PART`].
+    pub fn part(&self, part: u8) -> Option<&PartTiming> {
+        match part {
+            PARSE_PART => self.parse.as_ref(),
+            1 => self.part_1.as_ref(),
+            2 => self.part_2.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// Duration of a part in nanoseconds, or of the parse phase for [`PARSE_PART`].
+    pub fn part_nanos(&self, part: u8) -> Option<f64> {
+        self.part(part).map(|timing| timing.nanos)
+    }
+
+    /// Combined duration of the parse phase and both parts in nanoseconds.
+    pub fn total_nanos(&self) -> f64 {
+        [&self.parse, &self.part_1, &self.part_2]
+            .into_iter()
+            .flatten()
+            .map(|timing| timing.nanos)
+            .sum()
+    }
+
+    /// Whether heap statistics were recorded for any part of the day.
+    pub fn has_heap(&self) -> bool {
+        self.parse_heap.is_some() || self.part_1_heap.is_some() || self.part_2_heap.is_some()
+    }
+
+    /// Peak heap usage over all parts of the day.
+    pub fn peak_heap(&self) -> Option<HeapStats> {
+        [self.parse_heap, self.part_1_heap, self.part_2_heap]
+            .into_iter()
+            .flatten()
+            .reduce(|a, b| a.combine(&b))
+    }
+
+    fn copy_heap(&mut self, other: &Timing) {
+        self.parse_heap = other.parse_heap;
+        self.part_1_heap = other.part_1_heap;
+        self.part_2_heap = other.part_2_heap;
+    }
+
+    /// Build the timing of a day from the result records of its parts. Only solved parts and a successful parse phase are timed.
+    pub fn from_reports(day: Day, reports: &[PartReport]) -> Self {
+        let mut timing = Timing::empty(day);
+
+        for report in reports.iter().filter(|r| r.day == day) {
+            match report.part {
+                PARSE_PART => {}
+                1 => timing.part_1_status = report.status,
+                2 => timing.part_2_status = report.status,
+                _ => continue,
+            }
+
+            if report.status != Status::Solved {
+                continue;
+            }
+
+            let part_timing = Some(PartTiming {
+                nanos: report.duration_nanos,
+                samples: report.samples,
+                stats: report.stats,
+            });
+
+            match report.part {
+                PARSE_PART => {
+                    timing.parse = part_timing;
+                    timing.parse_heap = report.heap;
+                }
+                1 => {
+                    timing.part_1 = part_timing;
+                    timing.part_1_heap = report.heap;
+                }
+                2 => {
+                    timing.part_2 = part_timing;
+                    timing.part_2_heap = report.heap;
+                }
+                _ => continue,
+            }
+        }
+
+        timing
+    }
 }
 
 /// Represents benchmark times for a set of days.
@@ -22,27 +163,59 @@ pub struct Timings {
 }
 
 impl Timings {
-    /// Dehydrate timings to a JSON file.
-    pub fn store_file(&self) -> Result<(), Error> {
+    /// Dehydrate the timings of a year to a JSON file, using the current schema version.
+    pub fn store_file(&self, year: Year) -> Result<(), Error> {
         let json = JsonValue::from(self.clone());
-        let mut file = fs::File::create(TIMINGS_FILE_PATH)?;
+        let mut file = fs::File::create(get_timings_path(year))?;
         json.format_to(&mut file)
     }
 
-    /// Rehydrate timings from a JSON file. If not present, returns empty timings.
-    pub fn read_from_file() -> Self {
-        fs::read_to_string(TIMINGS_FILE_PATH)
-            .map_err(|x| x.to_string())
-            .and_then(Timings::try_from)
-            .unwrap_or_default()
+    /// Rehydrate the timings of a year from a JSON file. If not present, returns empty timings.
+    /// Files of older schema versions are migrated, they are rewritten the next time timings are stored.
+    /// Files that can not be read or migrated are an error, so that they are not overwritten with empty timings.
+    pub fn read_from_file(year: Year) -> Result<Self, String> {
+        let path = get_timings_path(year);
+
+        match fs::read_to_string(&path) {
+            Ok(contents) => Timings::try_from(contents).map_err(|e| format!("{path}: {e}")),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Timings::default()),
+            Err(e) => Err(format!("{path}: {e}")),
+        }
+    }
+
+    /// Rehydrate the stored timings of every year, newest year first.
+    pub fn read_all_years() -> Result<Vec<(Year, Self)>, String> {
+        let mut years: Vec<Year> = fs::read_dir("./data")
+            .map(|entries| {
+                entries
+                    .filter_map(Result::ok)
+                    .filter_map(|entry| entry.file_name().to_str()?.parse().ok())
+                    .filter(|year| Path::new(&get_timings_path(*year)).exists())
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        years.sort_unstable_by(|a, b| b.cmp(a));
+
+        years
+            .into_iter()
+            .map(|year| Ok((year, Timings::read_from_file(year)?)))
+            .collect()
     }
 
     /// Merge two sets of timings, overwriting `self` with `other` if present.
+    /// Heap statistics of `self` are kept for days that were not profiled in `other`.
     pub fn merge(&self, new: &Self) -> Self {
         let mut data: Vec<Timing> = vec![];
 
         for timing in &new.data {
-            data.push(timing.clone());
+            let mut timing = timing.clone();
+            if !timing.has_heap() {
+                if let Some(stored) = self.data.iter().find(|t| t.day == timing.day) {
+                    timing.copy_heap(stored);
+                }
+            }
+            data.push(timing);
         }
 
         for timing in &self.data {
@@ -51,13 +224,32 @@ impl Timings {
             }
         }
 
-        data.sort_unstable_by(|a, b| a.day.cmp(&b.day));
+        data.sort_unstable_by_key(|a| a.day);
+        Timings { data }
+    }
+
+    /// Merge the heap statistics of `new` into `self`, keeping the durations of `self`.
+    pub fn merge_heap(&self, new: &Self) -> Self {
+        let mut data = self.data.clone();
+
+        for timing in new.data.iter().filter(|t| t.has_heap()) {
+            match data.iter_mut().find(|t| t.day == timing.day) {
+                Some(stored) => stored.copy_heap(timing),
+                None => {
+                    let mut stored = Timing::empty(timing.day);
+                    stored.copy_heap(timing);
+                    data.push(stored);
+                }
+            }
+        }
+
+        data.sort_unstable_by_key(|a| a.day);
         Timings { data }
     }
 
     /// Sum up total duration of timings as millis.
     pub fn total_millis(&self) -> f64 {
-        self.data.iter().map(|x| x.total_nanos).sum::<f64>() / 1_000_000_f64
+        self.data.iter().map(Timing::total_nanos).sum::<f64>() / 1_000_000_f64
     }
 
     pub fn is_day_complete(&self, day: Day) -> bool {
@@ -73,6 +265,11 @@ impl From<Timings> for JsonValue {
     fn from(value: Timings) -> Self {
         let mut map: HashMap<String, JsonValue> = HashMap::new();
 
+        map.insert(
+            "version".into(),
+            JsonValue::Number(f64::from(TIMINGS_VERSION)),
+        );
+
         map.insert(
             "data".into(),
             JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
@@ -88,9 +285,27 @@ impl TryFrom<String> for Timings {
     fn try_from(value: String) -> Result<Self, Self::Error> {
         let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
 
-        let json_data = json
+        let document = json
             .get::<HashMap<String, JsonValue>>()
-            .ok_or("expected JSON document to be an object.")?
+            .ok_or("expected JSON document to be an object.")?;
+
+        // NOTE: files stored before the schema was versioned do not have a version.
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let version = match document.get("version") {
+            Some(v) => v
+                .get::<f64>()
+                .map(|x| *x as u8)
+                .ok_or("expected `json.version` to be a number.")?,
+            None => 1,
+        };
+
+        if version == 0 || version > TIMINGS_VERSION {
+            return Err(format!(
+                "unsupported timings version {version}, expected at most {TIMINGS_VERSION}."
+            ));
+        }
+
+        let json_data = document
             .get("data")
             .ok_or("expected JSON document to have key `data`.")?
             .get::<Vec<JsonValue>>()
@@ -99,12 +314,111 @@ impl TryFrom<String> for Timings {
         Ok(Timings {
             data: json_data
                 .iter()
-                .map(Timing::try_from)
+                .map(|timing| match version {
+                    1 => Timing::try_from(&migrate_v1(timing)?),
+                    _ => Timing::try_from(timing),
+                })
                 .collect::<Result<_, _>>()?,
         })
     }
 }
 
+/// Migrate a timing of schema version `1` to the current schema.
+/// Formatted durations are parsed back to nanoseconds. The number of samples is taken from the stored statistics, if any.
+fn migrate_v1(value: &JsonValue) -> Result<JsonValue, String> {
+    let mut json = value
+        .get::<HashMap<String, JsonValue>>()
+        .ok_or("Expected timing to be a JSON object.")?
+        .clone();
+
+    json.remove("total_nanos");
+
+    for key in ["parse", "part_1", "part_2"] {
+        let stats = json.remove(&format!("{key}_stats"));
+
+        let duration = match json.get(key) {
+            Some(v) if !v.is_null() => v
+                .get::<String>()
+                .ok_or(format!("Expected timing.{key} to be null or string."))?,
+            _ => continue,
+        };
+
+        let nanos = parse_duration_nanos(duration).ok_or(format!(
+            "Expected timing.{key} to be a duration, got `{duration}`."
+        ))?;
+
+        let samples = match &stats {
+            Some(stats) => {
+                let stats = BenchStats::try_from(stats)?;
+                stats.samples + stats.outliers
+            }
+            None => 1,
+        };
+
+        let mut part: HashMap<String, JsonValue> = HashMap::new();
+        part.insert("nanos".into(), JsonValue::Number(nanos));
+        #[allow(clippy::cast_precision_loss)]
+        part.insert("samples".into(), JsonValue::Number(samples as f64));
+        if let Some(stats) = stats {
+            part.insert("stats".into(), stats);
+        }
+
+        json.insert(key.into(), JsonValue::Object(part));
+    }
+
+    Ok(JsonValue::Object(json))
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&PartTiming> for JsonValue {
+    fn from(value: &PartTiming) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("nanos".into(), JsonValue::Number(value.nanos));
+
+        #[allow(clippy::cast_precision_loss)]
+        map.insert("samples".into(), JsonValue::Number(value.samples as f64));
+
+        if let Some(stats) = &value.stats {
+            map.insert("stats".into(), JsonValue::from(stats));
+        }
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for PartTiming {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected part timing to be a JSON object.")?;
+
+        let nanos = json
+            .get("nanos")
+            .and_then(|v| v.get::<f64>().copied())
+            .ok_or("Expected part timing.nanos to be a number.")?;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let samples = json
+            .get("samples")
+            .and_then(|v| v.get::<f64>())
+            .map(|x| *x as u128)
+            .ok_or("Expected part timing.samples to be a number.")?;
+
+        // NOTE: statistics are only present for benched parts.
+        let stats = json.get("stats").map(BenchStats::try_from).transpose()?;
+
+        Ok(PartTiming {
+            nanos,
+            samples,
+            stats,
+        })
+    }
+}
+
 /* -------------------------------------------------------------------------- */
 
 impl From<&Timing> for JsonValue {
@@ -112,27 +426,41 @@ impl From<&Timing> for JsonValue {
         let mut map: HashMap<String, JsonValue> = HashMap::new();
 
         map.insert("day".into(), JsonValue::String(value.day.to_string()));
-        map.insert("total_nanos".into(), JsonValue::Number(value.total_nanos));
 
-        let part_1 = value.part_1.clone().map(JsonValue::String);
-        let part_2 = value.part_2.clone().map(JsonValue::String);
+        for (key, timing) in [
+            ("parse", &value.parse),
+            ("part_1", &value.part_1),
+            ("part_2", &value.part_2),
+        ] {
+            map.insert(
+                key.into(),
+                match timing {
+                    Some(x) => JsonValue::from(x),
+                    None => JsonValue::Null,
+                },
+            );
+        }
 
         map.insert(
-            "part_1".into(),
-            match part_1 {
-                Some(x) => x,
-                None => JsonValue::Null,
-            },
+            "part_1_status".into(),
+            JsonValue::String(value.part_1_status.to_string()),
         );
 
         map.insert(
-            "part_2".into(),
-            match part_2 {
-                Some(x) => x,
-                None => JsonValue::Null,
-            },
+            "part_2_status".into(),
+            JsonValue::String(value.part_2_status.to_string()),
         );
 
+        for (key, heap) in [
+            ("parse_heap", &value.parse_heap),
+            ("part_1_heap", &value.part_1_heap),
+            ("part_2_heap", &value.part_2_heap),
+        ] {
+            if let Some(heap) = heap {
+                map.insert(key.into(), JsonValue::from(heap));
+            }
+        }
+
         JsonValue::Object(map)
     }
 }
@@ -151,26 +479,44 @@ impl TryFrom<&JsonValue> for Timing {
             .and_then(|day| Day::from_str(day).ok())
             .ok_or("Expected timing.day to be a Day struct.")?;
 
-        let part_1 = json
-            .get("part_1")
-            .map(|v| if v.is_null() { None } else { v.get::<String>() })
-            .ok_or("Expected timing.part_1 to be null or string.")?;
+        let part = |key: &str| match json.get(key) {
+            Some(v) if !v.is_null() => PartTiming::try_from(v).map(Some),
+            Some(_) => Ok(None),
+            None => Err(format!("Expected timing.{key} to be null or object.")),
+        };
 
-        let part_2 = json
-            .get("part_2")
-            .map(|v| if v.is_null() { None } else { v.get::<String>() })
-            .ok_or("Expected timing.part_2 to be null or string.")?;
+        // NOTE: the parse phase is optional, most solutions parse their input in each part.
+        let parse = match json.get("parse") {
+            Some(_) => part("parse")?,
+            None => None,
+        };
 
-        let total_nanos = json
-            .get("total_nanos")
-            .and_then(|v| v.get::<f64>().copied())
-            .ok_or("Expected timing.total_nanos to be a number.")?;
+        let part_1 = part("part_1")?;
+        let part_2 = part("part_2")?;
+
+        // NOTE: heap statistics are only present for profiled timings.
+        let heap = |key: &str| json.get(key).map(HeapStats::try_from).transpose();
+
+        // NOTE: statuses are optional as well, timings stored before they were tracked only contain solved parts.
+        let status = |key: &str, timing: Option<&PartTiming>| match json.get(key) {
+            Some(v) => v
+                .get::<String>()
+                .ok_or(format!("Expected timing.{key} to be a string."))?
+                .parse::<Status>(),
+            None if timing.is_some() => Ok(Status::Solved),
+            None => Ok(Status::NotSolved),
+        };
 
         Ok(Timing {
             day,
-            part_1: part_1.cloned(),
-            part_2: part_2.cloned(),
-            total_nanos,
+            parse,
+            part_1,
+            part_2,
+            part_1_heap: heap("part_1_heap")?,
+            part_2_heap: heap("part_2_heap")?,
+            parse_heap: heap("parse_heap")?,
+            part_1_status: status("part_1_status", part_1.as_ref())?,
+            part_2_status: status("part_2_status", part_2.as_ref())?,
         })
     }
 }
@@ -179,53 +525,84 @@ impl TryFrom<&JsonValue> for Timing {
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use crate::day;
+    use crate::{day, template::report::Status};
 
-    use super::{Timing, Timings};
+    use super::{PartTiming, Timing, Timings};
+
+    fn part_timing(nanos: f64) -> PartTiming {
+        PartTiming {
+            nanos,
+            samples: 1,
+            stats: None,
+        }
+    }
 
     fn get_mock_timings() -> Timings {
         Timings {
             data: vec![
                 Timing {
                     day: day!(1),
-                    part_1: Some("10ms".into()),
-                    part_2: Some("20ms".into()),
-                    total_nanos: 3e+10,
+                    parse: None,
+                    part_1: Some(part_timing(10_000_000.0)),
+                    part_2: Some(part_timing(20_000_000.0)),
+                    part_1_heap: None,
+                    part_2_heap: None,
+                    parse_heap: None,
+                    part_1_status: Status::Solved,
+                    part_2_status: Status::Solved,
                 },
                 Timing {
                     day: day!(2),
-                    part_1: Some("30ms".into()),
-                    part_2: Some("40ms".into()),
-                    total_nanos: 7e+10,
+                    parse: None,
+                    part_1: Some(part_timing(30_000_000.0)),
+                    part_2: Some(part_timing(40_000_000.0)),
+                    part_1_heap: None,
+                    part_2_heap: None,
+                    parse_heap: None,
+                    part_1_status: Status::Solved,
+                    part_2_status: Status::Solved,
                 },
                 Timing {
                     day: day!(4),
-                    part_1: Some("40ms".into()),
+                    parse: None,
+                    part_1: Some(part_timing(40_000_000.0)),
                     part_2: None,
-                    total_nanos: 4e+10,
+                    part_1_heap: None,
+                    part_2_heap: None,
+                    parse_heap: None,
+                    part_1_status: Status::Solved,
+                    part_2_status: Status::NotSolved,
                 },
             ],
         }
     }
 
     mod deserialization {
-        use crate::{day, template::timings::Timings};
+        use crate::{
+            day,
+            template::{report::Status, timings::Timings},
+            year,
+        };
 
         #[test]
         fn handles_json_timings() {
-            let json = r#"{ "data": [{ "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000000 }] }"#.to_string();
+            let json = r#"{ "version": 2, "data": [{ "day": "01", "part_1": { "nanos": 1000000, "samples": 10 }, "part_2": null, "part_1_status": "solved", "part_2_status": "not_solved" }] }"#.to_string();
             let timings = Timings::try_from(json).unwrap();
             assert_eq!(timings.data.len(), 1);
             let timing = timings.data.first().unwrap();
             assert_eq!(timing.day, day!(1));
-            assert_eq!(timing.part_1, Some("1ms".to_string()));
+            assert_eq!(timing.part_nanos(1), Some(1_000_000_f64));
+            assert_eq!(timing.part_1.unwrap().samples, 10);
             assert_eq!(timing.part_2, None);
-            assert_eq!(timing.total_nanos, 1_000_000_000_f64);
+            assert_eq!(timing.parse, None);
+            assert_eq!(timing.part_1_status, Status::Solved);
+            assert_eq!(timing.part_2_status, Status::NotSolved);
+            assert_eq!(timing.total_nanos(), 1_000_000_f64);
         }
 
         #[test]
         fn handles_empty_timings() {
-            let json = r#"{ "data": [] }"#.to_string();
+            let json = r#"{ "version": 2, "data": [] }"#.to_string();
             let timings = Timings::try_from(json).unwrap();
             assert_eq!(timings.data.len(), 0);
         }
@@ -240,13 +617,87 @@ mod tests {
         #[test]
         #[should_panic]
         fn panics_for_malformed_timings() {
-            let json = r#"{ "data": [{ "day": "01" }, { "day": "26" }, { "day": "02", "part_2": null, "total_nanos": 0 }] }"#.to_string();
+            let json = r#"{ "version": 2, "data": [{ "day": "01" }, { "day": "26" }, { "day": "02", "part_2": null }] }"#.to_string();
+            Timings::try_from(json).unwrap();
+        }
+
+        #[test]
+        fn errors_for_unknown_versions() {
+            let json = r#"{ "version": 3, "data": [] }"#.to_string();
+            assert_eq!(
+                Timings::try_from(json).err(),
+                Some("unsupported timings version 3, expected at most 2.".into())
+            );
+        }
+
+        #[test]
+        fn handles_missing_file() {
+            // no data is stored for years before the template existed.
+            let timings = Timings::read_from_file(year!(2015)).unwrap();
+            assert_eq!(timings.data.len(), 0);
+        }
+    }
+
+    mod migration {
+        use crate::{
+            day,
+            template::{report::Status, timings::Timings},
+        };
+
+        #[test]
+        fn migrates_unversioned_timings() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000 }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            assert_eq!(timings.data.len(), 1);
+            let timing = timings.data.first().unwrap();
+            assert_eq!(timing.day, day!(1));
+            assert_eq!(timing.part_nanos(1), Some(1_000_000_f64));
+            assert_eq!(timing.part_1.unwrap().samples, 1);
+            assert_eq!(timing.part_2, None);
+            assert_eq!(timing.part_1_status, Status::Solved);
+            assert_eq!(timing.part_2_status, Status::NotSolved);
+        }
+
+        #[test]
+        fn migrates_part_statuses() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": "1ms", "part_2": null, "part_1_status": "solved", "part_2_status": "timed_out", "total_nanos": 1000000 }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let timing = timings.data.first().unwrap();
+            assert_eq!(timing.part_1_status, Status::Solved);
+            assert_eq!(timing.part_2_status, Status::TimedOut);
+        }
+
+        #[test]
+        fn migrates_parse_timings() {
+            let json = r#"{ "data": [{ "day": "01", "parse": "2ms", "part_1": "1.5µs", "part_2": null, "total_nanos": 2001500 }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let timing = timings.data.first().unwrap();
+            assert_eq!(timing.part_nanos(0), Some(2_000_000_f64));
+            assert_eq!(timing.part_nanos(1), Some(1_500_f64));
+            assert_eq!(timing.total_nanos(), 2_001_500_f64);
+        }
+
+        #[test]
+        fn migrates_statistics() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": "74.1ms", "part_2": null, "total_nanos": 74100000, "part_1_stats": { "mean": 74200000, "median": 74100000, "min": 70000000, "max": 80000000, "p95": 79000000, "stddev": 100000, "samples": 95, "outliers": 5 } }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let part = timings.data.first().unwrap().part_1.unwrap();
+            assert_eq!(part.nanos, 74_100_000_f64);
+            assert_eq!(part.samples, 100);
+            assert_eq!(part.stats.map(|s| s.median), Some(74_100_000_f64));
+        }
+
+        #[test]
+        #[should_panic]
+        fn panics_for_malformed_durations() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": "fast", "part_2": null, "total_nanos": 0 }] }"#.to_string();
             Timings::try_from(json).unwrap();
         }
     }
 
     mod serialization {
         use super::get_mock_timings;
+        use crate::template::timings::Timings;
         use std::collections::HashMap;
         use tinyjson::JsonValue;
 
@@ -266,26 +717,50 @@ mod tests {
                 3
             );
         }
+
+        #[test]
+        fn roundtrips_timings() {
+            let timings = get_mock_timings();
+            let json = JsonValue::from(timings.clone()).stringify().unwrap();
+            let parsed = Timings::try_from(json).unwrap();
+            assert_eq!(parsed.data.len(), 3);
+            for (a, b) in timings.data.iter().zip(&parsed.data) {
+                assert_eq!(a.day, b.day);
+                assert_eq!(a.part_1, b.part_1);
+                assert_eq!(a.part_2, b.part_2);
+                assert_eq!(a.part_2_status, b.part_2_status);
+            }
+        }
     }
 
     mod is_day_complete {
         use crate::{
             day,
-            template::timings::{Timing, Timings},
+            template::{
+                report::Status,
+                timings::{Timing, Timings},
+            },
         };
 
+        use super::part_timing;
+
         #[test]
         fn handles_completed_days() {
             let timings = Timings {
                 data: vec![Timing {
                     day: day!(1),
-                    part_1: Some("1ms".into()),
-                    part_2: Some("2ms".into()),
-                    total_nanos: 3_000_000_000_f64,
+                    parse: None,
+                    part_1: Some(part_timing(1_000_000.0)),
+                    part_2: Some(part_timing(2_000_000.0)),
+                    part_1_heap: None,
+                    part_2_heap: None,
+                    parse_heap: None,
+                    part_1_status: Status::Solved,
+                    part_2_status: Status::Solved,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), true);
+            assert_eq!(timings.is_day_complete(day!(1)), true);
         }
 
         #[test]
@@ -293,13 +768,18 @@ mod tests {
             let timings = Timings {
                 data: vec![Timing {
                     day: day!(1),
-                    part_1: Some("1ms".into()),
+                    parse: None,
+                    part_1: Some(part_timing(1_000_000.0)),
                     part_2: None,
-                    total_nanos: 1_000_000_000_f64,
+                    part_1_heap: None,
+                    part_2_heap: None,
+                    parse_heap: None,
+                    part_1_status: Status::Solved,
+                    part_2_status: Status::NotSolved,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(day!(1)), false);
         }
 
         #[test]
@@ -307,23 +787,116 @@ mod tests {
             let timings = Timings {
                 data: vec![Timing {
                     day: day!(1),
+                    parse: None,
                     part_1: None,
                     part_2: None,
-                    total_nanos: 0.0,
+                    part_1_heap: None,
+                    part_2_heap: None,
+                    parse_heap: None,
+                    part_1_status: Status::NotSolved,
+                    part_2_status: Status::NotSolved,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(day!(1)), false);
+        }
+    }
+
+    mod from_reports {
+        use crate::{
+            day,
+            template::{
+                report::{PartReport, Status},
+                timings::Timing,
+            },
+        };
+
+        fn report(part: u8, status: Status, duration_nanos: f64) -> PartReport {
+            PartReport {
+                day: day!(1),
+                part,
+                answer: None,
+                status,
+                message: None,
+                duration_nanos,
+                samples: 100,
+                stats: None,
+                heap: None,
+                expected: None,
+            }
+        }
+
+        #[test]
+        fn handles_solved_parts() {
+            let timing = Timing::from_reports(
+                day!(1),
+                &[
+                    report(1, Status::Solved, 74.13),
+                    report(2, Status::Solved, 74_130_000.0),
+                ],
+            );
+            assert_eq!(timing.total_nanos(), 74_130_074.13);
+            assert_eq!(timing.part_nanos(1), Some(74.13));
+            assert_eq!(timing.part_1.unwrap().samples, 100);
+            assert_eq!(timing.part_1.unwrap().format(), "74.0ns");
+            assert_eq!(timing.part_2.unwrap().format(), "74.1ms");
+        }
+
+        #[test]
+        fn handles_parse_phase() {
+            let timing = Timing::from_reports(
+                day!(1),
+                &[
+                    report(0, Status::Solved, 2000.0),
+                    report(1, Status::Solved, 74.13),
+                    report(2, Status::Solved, 1000.0),
+                ],
+            );
+            assert_eq!(timing.total_nanos(), 3074.13);
+            assert_eq!(timing.parse.unwrap().format(), "2.0µs");
+            assert_eq!(timing.part_1.unwrap().format(), "74.0ns");
+        }
+
+        #[test]
+        fn handles_missing_parts() {
+            let timing = Timing::from_reports(
+                day!(1),
+                &[
+                    report(1, Status::NotSolved, 10.0),
+                    report(2, Status::NotSolved, 10.0),
+                ],
+            );
+            assert_eq!(timing.total_nanos(), 0_f64);
+            assert_eq!(timing.part_1.is_none(), true);
+            assert_eq!(timing.part_2.is_none(), true);
+        }
+
+        #[test]
+        fn handles_failed_parts() {
+            let timing = Timing::from_reports(
+                day!(1),
+                &[
+                    report(1, Status::Panicked, 0.0),
+                    report(2, Status::TimedOut, 1e9),
+                ],
+            );
+            assert_eq!(timing.total_nanos(), 0_f64);
+            assert_eq!(timing.part_1_status, Status::Panicked);
+            assert_eq!(timing.part_2_status, Status::TimedOut);
         }
     }
 
     mod merge {
         use crate::{
             day,
-            template::timings::{Timing, Timings},
+            template::{
+                report::Status,
+                stats::HeapStats,
+                timings::{Timing, Timings},
+            },
         };
 
-        use super::get_mock_timings;
+        use super::{get_mock_timings, part_timing};
 
         #[test]
         fn handles_disjunct_timings() {
@@ -331,9 +904,14 @@ mod tests {
             let other = Timings {
                 data: vec![Timing {
                     day: day!(3),
+                    parse: None,
                     part_1: None,
                     part_2: None,
-                    total_nanos: 0_f64,
+                    part_1_heap: None,
+                    part_2_heap: None,
+                    parse_heap: None,
+                    part_1_status: Status::NotSolved,
+                    part_2_status: Status::NotSolved,
                 }],
             };
             let merged = timings.merge(&other);
@@ -351,9 +929,14 @@ mod tests {
             let other = Timings {
                 data: vec![Timing {
                     day: day!(2),
+                    parse: None,
                     part_1: None,
                     part_2: None,
-                    total_nanos: 0_f64,
+                    part_1_heap: None,
+                    part_2_heap: None,
+                    parse_heap: None,
+                    part_1_status: Status::NotSolved,
+                    part_2_status: Status::NotSolved,
                 }],
             };
             let merged = timings.merge(&other);
@@ -361,7 +944,7 @@ mod tests {
             assert_eq!(merged.data.len(), 3);
             assert_eq!(merged.data[0].day, day!(1));
             assert_eq!(merged.data[1].day, day!(2));
-            assert_eq!(merged.data[1].total_nanos, 0_f64);
+            assert_eq!(merged.data[1].total_nanos(), 0_f64);
             assert_eq!(merged.data[2].day, day!(4));
         }
 
@@ -380,5 +963,47 @@ mod tests {
             let merged = timings.merge(&other);
             assert_eq!(merged.data.len(), 3);
         }
+
+        fn heap(peak_bytes: u64) -> HeapStats {
+            HeapStats {
+                peak_bytes,
+                total_allocations: 1,
+                total_bytes: peak_bytes,
+            }
+        }
+
+        #[test]
+        fn keeps_heap_stats_of_unprofiled_timings() {
+            let mut profiled = Timing::empty(day!(1));
+            profiled.part_1_heap = Some(heap(1024));
+            let timings = Timings {
+                data: vec![profiled],
+            };
+
+            let merged = timings.merge(&get_mock_timings());
+            assert_eq!(merged.data[0].part_1, Some(part_timing(10_000_000.0)));
+            assert_eq!(merged.data[0].part_1_heap, Some(heap(1024)));
+        }
+
+        #[test]
+        fn merges_heap_stats() {
+            let timings = get_mock_timings();
+
+            let mut profiled = Timing::empty(day!(2));
+            profiled.part_1 = Some(part_timing(1_000_000_000.0));
+            profiled.part_2_heap = Some(heap(2048));
+            let mut new_day = Timing::empty(day!(3));
+            new_day.part_1_heap = Some(heap(512));
+
+            let merged = timings.merge_heap(&Timings {
+                data: vec![profiled, new_day],
+            });
+            assert_eq!(merged.data.len(), 4);
+            assert_eq!(merged.data[1].part_1, Some(part_timing(30_000_000.0)));
+            assert_eq!(merged.data[1].part_2_heap, Some(heap(2048)));
+            assert_eq!(merged.data[2].day, day!(3));
+            assert_eq!(merged.data[2].part_1, None);
+            assert_eq!(merged.data[2].peak_heap(), Some(heap(512)));
+        }
     }
 }
@@ -0,0 +1,164 @@
+use std::env;
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// The first year of advent of code.
+const FIRST_YEAR: u16 = 2015;
+
+/// Environment variable that holds the default year, see `.cargo/config.toml`.
+pub const YEAR_ENV: &str = "AOC_YEAR";
+
+/// A valid year of advent of code (i.e. an integer from 2015 onwards).
+///
+/// # Display
+/// This value displays as a four digit number.
+///
+/// ```
+/// # use advent_of_code::template::Year;
+/// let year = Year::new(2024).unwrap();
+/// assert_eq!(year.to_string(), "2024")
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Year(u16);
+
+impl Year {
+    /// Creates a [`Year`] from the provided value if it's in the valid range,
+    /// returns [`None`] otherwise.
+    pub fn new(year: u16) -> Option<Self> {
+        if year < FIRST_YEAR {
+            return None;
+        }
+        Some(Self(year))
+    }
+
+    // Not part of the public API
+    #[doc(hidden)]
+    pub const fn __new_unchecked(year: u16) -> Self {
+        Self(year)
+    }
+
+    /// Parses the value of `AOC_YEAR` at compile time, used by solutions that do not specify their year.
+    // Not part of the public API
+    #[doc(hidden)]
+    pub const fn __from_env(value: Option<&str>) -> Self {
+        let Some(value) = value else {
+            panic!("`AOC_YEAR` is not set, pass the year to `solution!` like `solution!(1, year: 2024)`");
+        };
+
+        let bytes = value.as_bytes();
+        let mut year: u16 = 0;
+        let mut i = 0;
+
+        while i < bytes.len() {
+            assert!(
+                bytes[i].is_ascii_digit() && i < 4,
+                "invalid `AOC_YEAR`, expecting a year from 2015 onwards"
+            );
+            year = year * 10 + (bytes[i] - b'0') as u16;
+            i += 1;
+        }
+
+        assert!(
+            year >= FIRST_YEAR,
+            "invalid `AOC_YEAR`, expecting a year from 2015 onwards"
+        );
+
+        Self(year)
+    }
+
+    /// Reads the default year from the `AOC_YEAR` environment variable.
+    /// Returns [`None`] if it is not set, and an error if it is not a valid year.
+    pub fn from_env() -> Result<Option<Self>, String> {
+        match env::var(YEAR_ENV) {
+            Ok(value) => value
+                .parse()
+                .map(Some)
+                .map_err(|e| format!("invalid {YEAR_ENV} `{value}`: {e}")),
+            Err(env::VarError::NotPresent) => Ok(None),
+            Err(e) => Err(format!("invalid {YEAR_ENV}: {e}")),
+        }
+    }
+
+    /// Converts the [`Year`] into an [`u16`].
+    pub fn into_inner(self) -> u16 {
+        self.0
+    }
+}
+
+impl Display for Year {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl FromStr for Year {
+    type Err = YearFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let year = s.parse().map_err(|_| YearFromStrError)?;
+        Self::new(year).ok_or(YearFromStrError)
+    }
+}
+
+/// An error which can be returned when parsing a [`Year`].
+#[derive(Debug)]
+pub struct YearFromStrError;
+
+impl Error for YearFromStrError {}
+
+impl Display for YearFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting a year from 2015 onwards")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Creates a [`Year`] value in a const context.
+#[macro_export]
+macro_rules! year {
+    ($year:expr) => {{
+        const _ASSERT: () = assert!(
+            $year >= 2015,
+            concat!(
+                "invalid year `",
+                $year,
+                "`, expecting a year from 2015 onwards"
+            ),
+        );
+        $crate::template::Year::__new_unchecked($year)
+    }};
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Year;
+
+    #[test]
+    fn parses_years() {
+        assert_eq!("2024".parse::<Year>().ok(), Some(Year(2024)));
+        assert_eq!("2015".parse::<Year>().ok(), Some(Year(2015)));
+        assert!("2014".parse::<Year>().is_err());
+        assert!("24".parse::<Year>().is_err());
+        assert!("year".parse::<Year>().is_err());
+    }
+
+    #[test]
+    fn parses_years_at_compile_time() {
+        const YEAR: Year = Year::__from_env(Some("2023"));
+        assert_eq!(YEAR, Year(2023));
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_for_invalid_compile_time_years() {
+        Year::__from_env(Some("20x4"));
+    }
+}
+
+/* -------------------------------------------------------------------------- */